@@ -0,0 +1,183 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use crate::damage::Damage;
+use crate::gameover::Restart;
+use crate::minigun::MinigunState;
+use crate::player::Player;
+use crate::projectile::{spawn_projectile, Knockback, OnExpire, Projectile};
+use crate::recoil::ShotFired;
+use crate::weapons::{self, AltFire, Weapons};
+
+// ---------------- Constants ----------------
+pub const ALT_FIRE_KEY: KeyCode = KeyCode::E;
+
+// ---------------- Resources ----------------
+// Alt-fire cooldowns per weapon, plus whatever shot is in progress
+#[derive(Resource, Default)]
+pub struct AltFireState {
+    pub cooldowns: Vec<Timer>,
+    pub charge: Option<(usize, f32)>,         // weapon and seconds its charged shot has been held
+    pub burst: Option<(usize, u32, Timer)>,   // weapon, rounds left, time to the next round
+}
+
+impl AltFireState {
+    pub fn ready(&self, index: usize) -> bool {
+        self.cooldowns.get(index).is_none_or(|timer| timer.finished())
+    }
+}
+
+// ---------------- Components ----------------
+#[derive(Component)]
+pub struct AltFireText;
+
+// ---------------- Setup ----------------
+pub fn setup_alt_fire_ui(mut commands: Commands) {
+    commands.spawn(TextBundle {
+        text: Text::from_section("", TextStyle { font: Default::default(), font_size: 18.0, color: Color::WHITE }),
+        style: Style { position_type: PositionType::Absolute, left: Val::Px(10.0), bottom: Val::Px(45.0), ..default() },
+        ..default()
+    }).insert(AltFireText);
+}
+
+// ---------------- Systems ----------------
+#[allow(clippy::too_many_arguments)]
+pub fn alt_fire(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut weapons: ResMut<Weapons>,
+    minigun: Res<MinigunState>,
+    mut state: ResMut<AltFireState>,
+    player: Query<&Transform, With<Player>>,
+    mut shots: EventWriter<ShotFired>,
+) {
+    let n = weapons.stats.len();
+    if state.cooldowns.len() < n {
+        state.cooldowns.resize_with(n, || Timer::from_seconds(0.0, TimerMode::Once));
+    }
+    for cooldown in state.cooldowns.iter_mut() {
+        cooldown.tick(time.delta());
+    }
+    let Ok(player_t) = player.get_single() else { return };
+    let muzzle = |index: usize| player_t.translation + Vec3::new(0., weapons.stats[index].feel.barrel, 0.);
+
+    // A burst keeps firing after the trigger is released; its ammo was paid up front
+    if let Some((index, rounds, mut timer)) = state.burst.take() {
+        let stats = &weapons.stats[index];
+        if timer.tick(time.delta()).just_finished()
+            && let Some(AltFire::ExplosiveBurst { blast_radius, .. }) = stats.alt_fire.map(|alt| alt.mode)
+        {
+            let damage = stats.damage_for(index);
+            let origin = muzzle(index);
+            shots.send(ShotFired { weapon: index, origin });
+            let projectile = Projectile { on_expire: OnExpire::Explode(blast_radius), pierce: 0, falloff: 0., ..stats.projectile(Vec2::Y) };
+            spawn_projectile(&mut commands, origin, projectile, damage, Vec2::new(6., 12.), Color::ORANGE_RED);
+            commands.spawn(AudioBundle {
+                source: asset_server.load("audio/bullet.ogg"),
+                settings: PlaybackSettings::DESPAWN.with_speed(0.6),
+            });
+            if rounds > 1 { state.burst = Some((index, rounds - 1, timer)); }
+        } else {
+            state.burst = Some((index, rounds, timer));
+        }
+    }
+
+    let active = weapons.active;
+    // A charge belongs to the weapon it was started on; switching away drops it
+    if state.charge.is_some_and(|(index, _)| index != active) {
+        state.charge = None;
+    }
+    let Some(alt) = weapons.stats[active].alt_fire else {
+        state.charge = None;
+        return;
+    };
+    let usable = state.ready(active) && state.burst.is_none() && !weapons.is_reloading() && weapons.ammo[active].loaded >= alt.ammo_cost;
+    if !usable {
+        state.charge = None;
+        return;
+    }
+
+    let origin = muzzle(active);
+    let fired = match alt.mode {
+        AltFire::ChargedShot { charge_time, max_multiplier } => {
+            if keyboard.pressed(ALT_FIRE_KEY) {
+                let held = state.charge.map_or(0.0, |(_, held)| held);
+                state.charge = Some((active, held + time.delta_seconds()));
+                false
+            } else if let Some((_, held)) = state.charge.take() {
+                let stats = &weapons.stats[active];
+                let charge = (held / charge_time).min(1.0);
+                let multiplier = 1.0 + (max_multiplier - 1.0) * charge;
+                let damage = Damage { amount: stats.damage * multiplier, ..stats.damage_for(active) };
+                let projectile = Projectile { pierce: stats.pierce + charge as u32, falloff: 0., ..stats.projectile(Vec2::Y) };
+                spawn_projectile(&mut commands, origin, projectile, damage, Vec2::new(3., 7.) * (1.0 + charge), stats.color);
+                shots.send(ShotFired { weapon: active, origin });
+                commands.spawn(AudioBundle {
+                    source: asset_server.load("audio/bullet.ogg"),
+                    settings: PlaybackSettings::DESPAWN.with_speed(1.0 - 0.4 * charge).with_volume(Volume::new_relative(1.0 + charge)),
+                });
+                true
+            } else {
+                false
+            }
+        }
+        AltFire::Slug { damage, pierce } if keyboard.just_pressed(ALT_FIRE_KEY) => {
+            let stats = &weapons.stats[active];
+            let damage = Damage { amount: damage, ..stats.damage_for(active) };
+            let projectile = Projectile { pierce, falloff: 0., bounces: 0, ..stats.projectile(Vec2::Y) };
+            let mut slug = spawn_projectile(&mut commands, origin, projectile, damage, Vec2::new(5., 10.), Color::WHITE);
+            if stats.knockback > 0. {
+                slug.insert(Knockback(stats.knockback));
+            }
+            shots.send(ShotFired { weapon: active, origin });
+            commands.spawn(AudioBundle {
+                source: asset_server.load("audio/bullet.ogg"),
+                settings: PlaybackSettings::DESPAWN.with_speed(0.8),
+            });
+            true
+        }
+        AltFire::ExplosiveBurst { rounds, interval, .. } if keyboard.just_pressed(ALT_FIRE_KEY) => {
+            if active == weapons::MINIGUN && minigun.overheated() { return; }
+            // First round goes out on the next tick
+            let mut timer = Timer::from_seconds(interval, TimerMode::Repeating);
+            timer.tick(timer.duration() - std::time::Duration::from_nanos(1));
+            state.burst = Some((active, rounds, timer));
+            true
+        }
+        _ => false,
+    };
+
+    if fired {
+        weapons.ammo[active].loaded -= alt.ammo_cost;
+        state.cooldowns[active] = Timer::from_seconds(alt.cooldown, TimerMode::Once);
+    }
+}
+
+pub fn update_alt_fire_ui(weapons: Res<Weapons>, state: Res<AltFireState>, mut query: Query<&mut Text, With<AltFireText>>) {
+    let active = weapons.active;
+    let label = match weapons.stats[active].alt_fire {
+        None => String::new(),
+        Some(alt) => {
+            let status = match (state.charge, state.cooldowns.get(active)) {
+                (Some((_, held)), _) => match alt.mode {
+                    AltFire::ChargedShot { charge_time, .. } => format!("charging {:.0}%", (held / charge_time).min(1.0) * 100.0),
+                    _ => String::new(),
+                },
+                (None, Some(timer)) if !timer.finished() => format!("{:.1}s", timer.remaining_secs()),
+                _ if weapons.ammo[active].loaded < alt.ammo_cost => "no ammo".to_string(),
+                _ => "ready".to_string(),
+            };
+            format!("[E] {} ({} ammo): {}", alt.name, alt.ammo_cost, status)
+        }
+    };
+    for mut text in query.iter_mut() {
+        text.sections[0].value = label.clone();
+    }
+}
+
+pub fn reset_alt_fire(mut restarts: EventReader<Restart>, mut state: ResMut<AltFireState>) {
+    for _ in restarts.read() {
+        *state = AltFireState::default();
+    }
+}
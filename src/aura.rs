@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use crate::spatial::SpatialGrid;
+use crate::zombie::{Blast, Zombie};
+
+// ---------------- Components ----------------
+#[derive(Clone, Copy, Debug)]
+pub enum AuraEffect {
+    Heal(f32), // fraction of each target's max health per pulse
+}
+
+impl AuraEffect {
+    pub fn color(self) -> Color {
+        match self {
+            AuraEffect::Heal(_) => Color::rgba(0.2, 1.0, 0.3, 0.25),
+        }
+    }
+}
+
+// Pulses `effect` onto every zombie within `radius` (other than the source) each time `pulse` fires
+#[derive(Component)]
+pub struct Aura { pub radius: f32, pub effect: AuraEffect, pub pulse: Timer }
+
+impl Aura {
+    pub fn new(radius: f32, effect: AuraEffect, interval: f32) -> Self {
+        Self { radius, effect, pulse: Timer::from_seconds(interval, TimerMode::Repeating) }
+    }
+}
+
+// ---------------- Systems ----------------
+pub fn tick_auras(
+    mut commands: Commands,
+    time: Res<Time>,
+    grid: Res<SpatialGrid>,
+    mut auras: Query<(Entity, &mut Aura, &Transform)>,
+    mut zombies: Query<&mut Zombie>,
+) {
+    for (source, mut aura, t) in auras.iter_mut() {
+        if !aura.pulse.tick(time.delta()).just_finished() { continue; }
+
+        for (target, _) in grid.query_radius(t.translation.truncate(), aura.radius) {
+            if target == source { continue; }
+            match aura.effect {
+                AuraEffect::Heal(fraction) => {
+                    if let Ok(mut z) = zombies.get_mut(target) && z.health > 0.0 {
+                        z.health = (z.health + z.max_health * fraction).min(z.max_health);
+                    }
+                }
+            }
+        }
+
+        // Visible pulse so the player can spot the source
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(t.translation.truncate().extend(-0.5)),
+            sprite: Sprite { color: aura.effect.color(), custom_size: Some(Vec2::splat(aura.radius * 2.0)), ..default() },
+            ..default()
+        }).insert(Blast { timer: Timer::from_seconds(0.4, TimerMode::Once), alpha: aura.effect.color().a() });
+    }
+}
@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::gameover::{GameOver, Restart};
+use crate::spawn_pattern::{self, SpawnPattern};
+use crate::wave::GameMode;
+use crate::zombie::{self, Zombie, ZombieAttributes, ZombieFrames, ZombieKind, ZombieStats};
+
+// ---------------- Constants ----------------
+pub const ENDLESS_BOSS_INTERVAL: f32 = 90.0;
+pub const CHARGE_INTERVAL: f32 = 4.0;
+pub const CHARGE_DURATION: f32 = 0.75;
+pub const CHARGE_SPEED_MULTIPLIER: f32 = 4.0;
+pub const BOSS_SUMMON_INTERVAL: f32 = 5.0;
+pub const BOSS_SUMMON_COUNT: usize = 3;
+pub const ENRAGE_SPEED_MULTIPLIER: f32 = 2.5;
+pub const BOSS_BAR_WIDTH: f32 = 400.0;
+
+// ---------------- Components ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BossPhase {
+    Charge,  // above 2/3 health: periodic dashes forward
+    Summon,  // above 1/3 health: calls in walkers around itself
+    Enrage,  // below 1/3 health: permanently faster
+}
+
+impl BossPhase {
+    pub fn from_health(health: f32, max_health: f32) -> Self {
+        let ratio = health / max_health;
+        if ratio > 2.0 / 3.0 {
+            BossPhase::Charge
+        } else if ratio > 1.0 / 3.0 {
+            BossPhase::Summon
+        } else {
+            BossPhase::Enrage
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Boss { pub phase: BossPhase, pub ability_timer: Timer, pub charge: Timer }
+
+impl Default for Boss {
+    fn default() -> Self {
+        let mut charge = Timer::from_seconds(CHARGE_DURATION, TimerMode::Once);
+        charge.tick(charge.duration()); // not charging on spawn
+        Self {
+            phase: BossPhase::Charge,
+            ability_timer: Timer::from_seconds(CHARGE_INTERVAL, TimerMode::Repeating),
+            charge,
+        }
+    }
+}
+
+#[derive(Component)] pub struct BossBar;
+#[derive(Component)] pub struct BossBarFill;
+
+// ---------------- Resources ----------------
+#[derive(Resource)] pub struct EndlessBossTimer(pub Timer);
+
+// ---------------- Spawning ----------------
+pub fn spawn_endless_boss(
+    mut commands: Commands,
+    time: Res<Time>,
+    mode: Res<GameMode>,
+    game_over: Res<GameOver>,
+    mut timer: ResMut<EndlessBossTimer>,
+    bosses: Query<(), With<Boss>>,
+) {
+    if *mode != GameMode::Endless || game_over.0 { return; }
+    if timer.0.tick(time.delta()).just_finished() && bosses.is_empty() {
+        spawn_pattern::queue_group(&mut commands, SpawnPattern::TopEdge, &[ZombieKind::Boss]);
+        println!("A boss approaches!");
+    }
+}
+
+pub fn reset_boss_timer(mut restarts: EventReader<Restart>, mut timer: ResMut<EndlessBossTimer>) {
+    for _ in restarts.read() {
+        timer.0.reset();
+    }
+}
+
+// ---------------- Behaviour ----------------
+pub fn boss_behaviour(
+    mut commands: Commands,
+    time: Res<Time>,
+    frames: Res<ZombieFrames>,
+    stats: Res<ZombieStats>,
+    mut query: Query<(&mut Boss, &Zombie, &ZombieAttributes, &mut Transform, &mut Sprite)>,
+) {
+    for (mut boss, z, attributes, mut t, mut sprite) in query.iter_mut() {
+        let phase = BossPhase::from_health(z.health, z.max_health);
+        if phase != boss.phase {
+            boss.phase = phase;
+            match phase {
+                BossPhase::Charge => {}
+                BossPhase::Summon => {
+                    boss.ability_timer = Timer::from_seconds(BOSS_SUMMON_INTERVAL, TimerMode::Repeating);
+                    println!("The boss calls for help!");
+                }
+                BossPhase::Enrage => {
+                    sprite.color = Color::rgb(1.0, 0.4, 0.4);
+                    println!("The boss is enraged!");
+                }
+            }
+        }
+
+        boss.charge.tick(time.delta());
+        let ability_ready = boss.ability_timer.tick(time.delta()).just_finished();
+
+        let multiplier = match boss.phase {
+            BossPhase::Charge => {
+                if ability_ready { boss.charge.reset(); }
+                if boss.charge.finished() { 1.0 } else { CHARGE_SPEED_MULTIPLIER }
+            }
+            BossPhase::Summon => {
+                if ability_ready {
+                    let mut rng = rand::rng();
+                    for _ in 0..BOSS_SUMMON_COUNT {
+                        let offset = Vec3::new(rng.random_range(-60.0..60.0), rng.random_range(-20.0..40.0), 0.0);
+                        let pos = t.translation + offset;
+                        let pos = Vec3::new(pos.x.clamp(-375.0, 375.0), pos.y, 0.0);
+                        zombie::spawn_zombie(&mut commands, &frames, &stats, ZombieKind::Walker, pos);
+                    }
+                }
+                1.0
+            }
+            BossPhase::Enrage => ENRAGE_SPEED_MULTIPLIER,
+        };
+
+        t.translation.y -= attributes.current.speed * multiplier * time.delta_seconds();
+    }
+}
+
+// ---------------- Health Bar ----------------
+pub fn setup_boss_ui(mut commands: Commands) {
+    commands.spawn((NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px((800.0 - BOSS_BAR_WIDTH) / 2.0),
+            top: Val::Px(50.0),
+            width: Val::Px(BOSS_BAR_WIDTH),
+            height: Val::Px(16.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+        border_color: Color::WHITE.into(),
+        visibility: Visibility::Hidden,
+        ..default()
+    }, BossBar))
+    .with_children(|parent| {
+        parent.spawn((NodeBundle {
+            style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), ..default() },
+            background_color: Color::CRIMSON.into(),
+            ..default()
+        }, BossBarFill));
+        parent.spawn(TextBundle {
+            text: Text::from_section("BOSS", TextStyle { font: Default::default(), font_size: 14.0, color: Color::WHITE }),
+            style: Style { position_type: PositionType::Absolute, left: Val::Px(4.0), ..default() },
+            ..default()
+        });
+    });
+}
+
+pub fn update_boss_bar(
+    bosses: Query<&Zombie, With<Boss>>,
+    mut bar: Query<&mut Visibility, With<BossBar>>,
+    mut fill: Query<&mut Style, With<BossBarFill>>,
+) {
+    let boss = bosses.iter().next();
+
+    for mut visibility in bar.iter_mut() {
+        *visibility = if boss.is_some() { Visibility::Visible } else { Visibility::Hidden };
+    }
+
+    if let Some(z) = boss {
+        for mut style in fill.iter_mut() {
+            style.width = Val::Percent(100.0 * (z.health / z.max_health).max(0.0));
+        }
+    }
+}
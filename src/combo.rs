@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use crate::damage::{PlayerDamageEvent, ZombieKilled};
+use crate::gameover::Restart;
+use crate::powerup::{Buffs, PowerUpKind};
+
+// ---------------- Constants ----------------
+pub const COMBO_WINDOW: f32 = 2.0;       // seconds after a kill before the combo starts to decay
+pub const COMBO_DECAY: f32 = 0.4;        // seconds per kill lost once idle
+pub const KILLS_PER_STEP: u32 = 5;
+pub const MULTIPLIER_STEP: f32 = 0.5;
+pub const MAX_MULTIPLIER: f32 = 4.0;
+
+// ---------------- Resources ----------------
+#[derive(Resource)]
+pub struct Combo {
+    pub kills: u32,
+    pub window: Timer,
+    pub decay: Timer,
+}
+
+impl Default for Combo {
+    fn default() -> Self {
+        Self {
+            kills: 0,
+            window: Timer::from_seconds(COMBO_WINDOW, TimerMode::Once),
+            decay: Timer::from_seconds(COMBO_DECAY, TimerMode::Repeating),
+        }
+    }
+}
+
+impl Combo {
+    pub fn multiplier(&self) -> f32 {
+        (1.0 + (self.kills / KILLS_PER_STEP) as f32 * MULTIPLIER_STEP).min(MAX_MULTIPLIER)
+    }
+
+    // Score for `base` points at the current multiplier
+    pub fn points(&self, base: u32) -> u32 {
+        (base as f32 * self.multiplier()).round() as u32
+    }
+}
+
+// ---------------- Components ----------------
+#[derive(Component)]
+pub struct ComboText;
+
+// ---------------- Setup ----------------
+pub fn setup_combo_ui(mut commands: Commands) {
+    commands.spawn(TextBundle {
+        text: Text::from_section("", TextStyle { font: Default::default(), font_size: 24.0, color: Color::ORANGE }),
+        style: Style { position_type: PositionType::Absolute, right: Val::Px(10.0), bottom: Val::Px(75.0), ..default() },
+        ..default()
+    }).insert(ComboText);
+}
+
+// ---------------- Systems ----------------
+pub fn update_combo(
+    time: Res<Time>,
+    buffs: Res<Buffs>,
+    mut killed: EventReader<ZombieKilled>,
+    mut player_damage: EventReader<PlayerDamageEvent>,
+    mut combo: ResMut<Combo>,
+) {
+    let kills = killed.read().count() as u32;
+    if kills > 0 {
+        combo.kills += kills;
+        combo.window.reset();
+        combo.decay.reset();
+    } else if combo.window.tick(time.delta()).finished() && combo.decay.tick(time.delta()).just_finished() {
+        combo.kills = combo.kills.saturating_sub(1);
+    }
+
+    // Taking a hit breaks the combo, unless the shield soaked it up
+    let hurt = player_damage.read().any(|event| event.amount > 0.);
+    if hurt && !buffs.has(PowerUpKind::Shield) {
+        combo.kills = 0;
+    }
+}
+
+pub fn update_combo_ui(combo: Res<Combo>, mut query: Query<(&mut Text, &mut Style), With<ComboText>>) {
+    for (mut text, mut style) in query.iter_mut() {
+        style.display = if combo.kills > 0 { Display::Flex } else { Display::None };
+        text.sections[0].value = format!("Combo {}  x{:.1}", combo.kills, combo.multiplier());
+        // Grows with the multiplier so a hot streak is hard to miss
+        text.sections[0].style.font_size = 20.0 + 4.0 * combo.multiplier();
+    }
+}
+
+pub fn reset_combo(mut restarts: EventReader<Restart>, mut combo: ResMut<Combo>) {
+    for _ in restarts.read() {
+        *combo = Combo::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn combo(kills: u32) -> Combo {
+        Combo { kills, ..Combo::default() }
+    }
+
+    #[test]
+    fn multiplier_steps_every_few_kills() {
+        assert_eq!(combo(0).multiplier(), 1.0);
+        assert_eq!(combo(KILLS_PER_STEP - 1).multiplier(), 1.0);
+        assert_eq!(combo(KILLS_PER_STEP).multiplier(), 1.0 + MULTIPLIER_STEP);
+        assert_eq!(combo(KILLS_PER_STEP * 3).multiplier(), 1.0 + 3.0 * MULTIPLIER_STEP);
+    }
+
+    #[test]
+    fn multiplier_is_capped() {
+        assert_eq!(combo(1000).multiplier(), MAX_MULTIPLIER);
+    }
+
+    #[test]
+    fn points_round_to_the_nearest_whole_point() {
+        assert_eq!(combo(0).points(10), 10);
+        assert_eq!(combo(KILLS_PER_STEP).points(10), 15);
+        assert_eq!(combo(KILLS_PER_STEP).points(5), 8);
+        assert_eq!(combo(1000).points(25), 100);
+    }
+}
@@ -0,0 +1,189 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use rand::Rng;
+use crate::combo::Combo;
+use crate::elite::{Elite, Shield};
+use crate::explosion::{Explosion, SELF_DAMAGE};
+use crate::gameover::GameOver;
+use crate::melee::MELEE_KILL_BONUS;
+use crate::player::PlayerHealth;
+use crate::powerup::{Buffs, PowerUpKind, DOUBLE_DAMAGE};
+use crate::score::{Coins, Score, spawn_floating_score, spawn_floating_text};
+use crate::stats::RunStats;
+use crate::status::{StatusEffects, StatusKind, SHOCK_VULNERABILITY};
+use crate::zombie::{Zombie, ZombieAttributes, ZombieKind};
+
+pub const HEAD_FRACTION: f32 = 0.2; // radius of the head as a fraction of zombie size
+pub const HEAD_OFFSET: f32 = 0.35;  // how far ahead of the centre the head sits, along the zombie's facing
+pub const CRIT_TEXT_SIZE: f32 = 30.0;
+pub const HIT_POINTS: u32 = 10;     // score for each direct hit, before the combo multiplier
+
+// ---------------- Damage Types ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DamageType { Ballistic, Explosive, Fire, Poison, Electric, Melee }
+
+impl DamageType {
+    // Fraction of the target's armour that still applies
+    pub fn armour_factor(self) -> f32 {
+        match self {
+            DamageType::Ballistic | DamageType::Melee => 1.0,
+            DamageType::Explosive | DamageType::Fire | DamageType::Electric => 0.5,
+            DamageType::Poison => 0.0,
+        }
+    }
+}
+
+// Carried by every projectile; `source` is the index of the weapon that fired it, or None (grenades).
+// `direct` is false for weapons that hit many times a second (beams, flames), which don't score per hit.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Damage { pub amount: f32, pub kind: DamageType, pub crit_chance: f32, pub crit_multiplier: f32, pub source: Option<usize>, pub direct: bool }
+
+impl Damage {
+    // Builds the event for one hit on `target`; headshots always crit, anything else rolls for it.
+    // Weapons with a multiplier of 1 never crit.
+    pub fn hit(&self, target: Entity, headshot: bool, rng: &mut impl Rng) -> DamageEvent {
+        let crit = self.crit_multiplier > 1. && (headshot || rng.random_bool(self.crit_chance.clamp(0., 1.) as f64));
+        let amount = if crit { self.amount * self.crit_multiplier } else { self.amount };
+        DamageEvent { target, amount, kind: self.kind, source: self.source, crit, direct: self.direct }
+    }
+
+    // Area version for explosive projectiles; the player takes a share if caught in the blast
+    pub fn explosion(&self, position: Vec3, radius: f32) -> Explosion {
+        Explosion { position, radius, damage: self.amount, player_damage: self.amount * SELF_DAMAGE, source: self.source, status: None }
+    }
+}
+
+// ---------------- Events ----------------
+// Everything that hurts a zombie goes through here so kills are only counted once.
+// `source` is the weapon index, or None for damage not fired from a weapon (grenades, exploders).
+// Only `direct` hits (a bullet, pellet or swing landing) score points; blasts and damage over time don't.
+#[derive(Event)]
+pub struct DamageEvent { pub target: Entity, pub amount: f32, pub kind: DamageType, pub source: Option<usize>, pub crit: bool, pub direct: bool }
+
+// Zombies turn to face where they're walking, so the head is a small circle pushed forward along
+// their facing. A shot travelling from `from` along `direction` (normalised) is a headshot if its
+// path still crosses that circle.
+pub fn is_head_hit(from: Vec2, direction: Vec2, zombie: &Transform, size: f32) -> bool {
+    let head = (zombie.translation + zombie.rotation * Vec3::X * size * HEAD_OFFSET).truncate();
+    let along = (head - from).dot(direction).max(0.);
+    (from + direction * along).distance(head) < size * HEAD_FRACTION
+}
+
+#[derive(Event)]
+pub struct ZombieKilled { pub kind: ZombieKind, pub position: Vec3 }
+
+#[derive(Event)]
+pub struct PlayerDamageEvent { pub amount: f32 }
+
+// ---------------- Zombies ----------------
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn apply_zombie_damage(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut events: EventReader<DamageEvent>,
+    mut zombies: Query<(&mut Zombie, &ZombieAttributes, &Transform, Option<&mut Shield>, Option<&Elite>, Option<&StatusEffects>)>,
+    mut score: ResMut<Score>,
+    mut coins: ResMut<Coins>,
+    mut run_stats: ResMut<RunStats>,
+    mut killed: EventWriter<ZombieKilled>,
+    buffs: Res<Buffs>,
+    combo: Res<Combo>,
+) {
+    let mut rng = rand::rng();
+    for event in events.read() {
+        let Ok((mut z, attributes, t, shield, elite, statuses)) = zombies.get_mut(event.target) else { continue };
+        if z.health <= 0. { continue; } // already dying this frame
+
+        let mut amount = event.amount;
+        // Double Damage boosts weapon damage, including its burns and shocks
+        if event.source.is_some() && buffs.has(PowerUpKind::DoubleDamage) { amount *= DOUBLE_DAMAGE; }
+        if statuses.is_some_and(|s| s.has(StatusKind::Shocked)) { amount *= SHOCK_VULNERABILITY; }
+        // Shields soak damage before armour and health
+        if let Some(mut shield) = shield {
+            let absorbed = amount.min(shield.current);
+            shield.current -= absorbed;
+            amount -= absorbed;
+        }
+        let armour = attributes.current.armour.clamp(0., 0.9) * event.kind.armour_factor();
+        let dealt = amount * (1. - armour);
+        z.health -= dealt;
+        // The combo multiplier scales score, not coins
+        let hit_points = if event.direct { combo.points(HIT_POINTS) } else { 0 };
+        if hit_points > 0 {
+            score.0 += hit_points;
+            spawn_floating_score(&mut commands, t.translation, &format!("+{}", hit_points), Color::YELLOW, &mut rng);
+        }
+        if let Some(weapon) = event.source { run_stats.record_hit(weapon, dealt, event.crit); }
+        // Crits show the damage dealt in big red numbers with a sharp ping
+        if event.crit {
+            spawn_floating_text(&mut commands, t.translation, &format!("{:.0}!", dealt), Color::CRIMSON, CRIT_TEXT_SIZE, &mut rng);
+            commands.spawn(AudioBundle {
+                source: asset_server.load("audio/crit.ogg"),
+                settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(0.7)),
+            });
+        }
+
+        if z.health <= 0. {
+            commands.entity(event.target).despawn_recursive();
+            let multiplier = elite.map_or(1., |e| e.reward_multiplier());
+            let reward = (z.kind.profile().reward as f32 * multiplier) as u32;
+            // A direct hit already paid out its share of the reward
+            let kill_points = combo.points(reward.saturating_sub(if event.direct { HIT_POINTS } else { 0 }));
+            score.0 += kill_points;
+            coins.0 += reward;
+            let label = if combo.multiplier() > 1. {
+                format!("+{} x{:.1}", hit_points + kill_points, combo.multiplier())
+            } else {
+                format!("+{}", reward)
+            };
+            spawn_floating_score(&mut commands, t.translation, &label, Color::GOLD, &mut rng);
+            // Reward the risk of finishing zombies up close
+            if event.kind == DamageType::Melee {
+                let bonus = combo.points(MELEE_KILL_BONUS);
+                score.0 += bonus;
+                spawn_floating_score(&mut commands, t.translation, &format!("MELEE +{}", bonus), Color::ORANGE_RED, &mut rng);
+            }
+            if let Some(weapon) = event.source { run_stats.record_kill(weapon); }
+            killed.send(ZombieKilled { kind: z.kind, position: t.translation });
+        }
+    }
+}
+
+// ---------------- Player ----------------
+pub fn apply_player_damage(
+    mut events: EventReader<PlayerDamageEvent>,
+    mut health: ResMut<PlayerHealth>,
+    game_over: Res<GameOver>,
+    buffs: Res<Buffs>,
+) {
+    for event in events.read() {
+        if game_over.0 || buffs.has(PowerUpKind::Shield) { continue; }
+        health.current = (health.current - event.amount).max(0.);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A zombie at the origin, facing `angle` radians from +X
+    fn zombie(angle: f32) -> Transform {
+        Transform::from_rotation(Quat::from_rotation_z(angle))
+    }
+
+    #[test]
+    fn head_follows_facing() {
+        // Facing right, the head is at (14, 0); a shot straight up through it is a headshot
+        assert!(is_head_hit(Vec2::new(14., -100.), Vec2::Y, &zombie(0.), 40.));
+        // Straight up the middle only grazes the body
+        assert!(!is_head_hit(Vec2::new(0., -100.), Vec2::Y, &zombie(0.), 40.));
+        // Facing down towards the player, the middle of the zombie lines up with the head
+        assert!(is_head_hit(Vec2::new(0., -100.), Vec2::Y, &zombie(-std::f32::consts::FRAC_PI_2), 40.));
+    }
+
+    #[test]
+    fn shots_past_the_head_miss_it() {
+        // Facing down, the head is at (0, -14); a bullet already above it has gone past
+        assert!(!is_head_hit(Vec2::new(0., 10.), Vec2::Y, &zombie(-std::f32::consts::FRAC_PI_2), 40.));
+    }
+}
@@ -0,0 +1,176 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::player::Player;
+use crate::zombie::{Attributes, Zombie, ZombieAttributes};
+
+// ---------------- Constants ----------------
+pub const ELITE_CHANCE_PER_LEVEL: f32 = 0.03;
+pub const MAX_ELITE_CHANCE: f32 = 0.4;
+pub const EXTRA_AFFIX_CHANCE: f32 = 0.25;
+pub const MAX_AFFIXES: usize = 3;
+pub const REWARD_PER_AFFIX: f32 = 0.5;
+pub const REGEN_PER_SECOND: f32 = 0.03; // fraction of max health
+pub const SHIELD_FRACTION: f32 = 0.5;   // of max health
+pub const ENRAGE_THRESHOLD: f32 = 0.35;
+pub const ENRAGE_MULTIPLIER: f32 = 1.5;
+
+// ---------------- Affixes ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Affix { Armoured, Fast, Regenerating, Shielded, Vampiric, Enraged }
+
+impl Affix {
+    pub const ALL: [Affix; 6] = [Affix::Armoured, Affix::Fast, Affix::Regenerating, Affix::Shielded, Affix::Vampiric, Affix::Enraged];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Affix::Armoured => "Armoured",
+            Affix::Fast => "Fast",
+            Affix::Regenerating => "Regenerating",
+            Affix::Shielded => "Shielded",
+            Affix::Vampiric => "Vampiric",
+            Affix::Enraged => "Enraged",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Affix::Armoured => Color::SILVER,
+            Affix::Fast => Color::CYAN,
+            Affix::Regenerating => Color::LIME_GREEN,
+            Affix::Shielded => Color::BLUE,
+            Affix::Vampiric => Color::PURPLE,
+            Affix::Enraged => Color::ORANGE_RED,
+        }
+    }
+}
+
+// ---------------- Components ----------------
+#[derive(Component)]
+pub struct Elite { pub affixes: Vec<Affix> }
+
+impl Elite {
+    pub fn has(&self, affix: Affix) -> bool { self.affixes.contains(&affix) }
+
+    pub fn reward_multiplier(&self) -> f32 { 1.0 + REWARD_PER_AFFIX * self.affixes.len() as f32 }
+}
+
+#[derive(Component)]
+pub struct Shield { pub current: f32, pub max: f32 }
+
+#[derive(Component)] pub struct ShieldBar;
+
+// Child that stays upright above its parent regardless of the parent's heading
+#[derive(Component)]
+pub struct UprightLabel { pub offset: Vec3 }
+
+// ---------------- Rolling ----------------
+// The chance of an elite, and of extra affixes, scales with `ZombieStats.level`
+pub fn roll_affixes(level: u32, rng: &mut impl Rng) -> Vec<Affix> {
+    let chance = (level as f32 * ELITE_CHANCE_PER_LEVEL).min(MAX_ELITE_CHANCE);
+    if !rng.random_bool(chance as f64) { return Vec::new(); }
+
+    let mut affixes = vec![Affix::ALL[rng.random_range(0..Affix::ALL.len())]];
+    while affixes.len() < MAX_AFFIXES && rng.random_bool((EXTRA_AFFIX_CHANCE * chance / MAX_ELITE_CHANCE) as f64) {
+        let affix = Affix::ALL[rng.random_range(0..Affix::ALL.len())];
+        if !affixes.contains(&affix) { affixes.push(affix); }
+    }
+    affixes
+}
+
+// Permanent stat changes are baked into the spawn snapshot
+pub fn apply_affixes(mut attributes: Attributes, affixes: &[Affix]) -> Attributes {
+    for affix in affixes {
+        match affix {
+            Affix::Armoured => attributes.armour += 0.3,
+            Affix::Fast => attributes.speed *= 1.4,
+            _ => {}
+        }
+    }
+    attributes
+}
+
+pub fn attach_elite(commands: &mut Commands, entity: Entity, affixes: Vec<Affix>, max_health: f32, size: f32) {
+    let label = affixes.iter().map(|a| a.name()).collect::<Vec<_>>().join(" ");
+    let shielded = affixes.contains(&Affix::Shielded);
+    let aura = affixes[0].color().with_a(0.35);
+
+    let mut elite = commands.entity(entity);
+    if shielded {
+        elite.insert(Shield { current: max_health * SHIELD_FRACTION, max: max_health * SHIELD_FRACTION });
+    }
+    elite.insert(Elite { affixes });
+    elite.with_children(|parent| {
+        // Aura behind the sprite
+        parent.spawn(SpriteBundle {
+            transform: Transform::from_xyz(0.0, 0.0, -0.1),
+            sprite: Sprite { color: aura, custom_size: Some(Vec2::splat(size * 1.6)), ..default() },
+            ..default()
+        });
+        if shielded {
+            parent.spawn(SpriteBundle {
+                transform: Transform::from_xyz(0.0, size * 0.8 + 5.0, 1.0),
+                sprite: Sprite { color: Color::CYAN, custom_size: Some(Vec2::new(25.0, 3.0)), ..default() },
+                ..default()
+            }).insert(ShieldBar);
+        }
+        parent.spawn(Text2dBundle {
+            text: Text::from_section(label, TextStyle { font: Default::default(), font_size: 10.0, color: Color::GOLD }),
+            ..default()
+        }).insert(UprightLabel { offset: Vec3::new(0.0, size * 0.8 + 14.0, 2.0) });
+    });
+}
+
+// ---------------- Behaviour ----------------
+pub fn regenerate_elites(time: Res<Time>, mut query: Query<(&Elite, &mut Zombie)>) {
+    for (elite, mut z) in query.iter_mut() {
+        if elite.has(Affix::Regenerating) && z.health > 0.0 {
+            z.health = (z.health + z.max_health * REGEN_PER_SECOND * time.delta_seconds()).min(z.max_health);
+        }
+    }
+}
+
+// Vampiric elites heal by the contact damage they deal
+pub fn vampiric_drain(
+    time: Res<Time>,
+    mut query: Query<(&Elite, &mut Zombie, &ZombieAttributes, &Transform)>,
+    player: Query<&Transform, (With<Player>, Without<Zombie>)>,
+) {
+    let Ok(player_t) = player.get_single() else { return };
+    for (elite, mut z, attributes, t) in query.iter_mut() {
+        if !elite.has(Affix::Vampiric) || z.health <= 0.0 { continue; }
+        if t.translation.truncate().distance(player_t.translation.truncate()) < z.kind.profile().size * 0.5 + 8.0 {
+            z.health = (z.health + attributes.current.damage * time.delta_seconds()).min(z.max_health);
+        }
+    }
+}
+
+// Runs in `AttributeSet::Modify`
+pub fn enrage_elites(mut query: Query<(&Elite, &Zombie, &mut ZombieAttributes)>) {
+    for (elite, z, mut attributes) in query.iter_mut() {
+        if elite.has(Affix::Enraged) && z.health < z.max_health * ENRAGE_THRESHOLD {
+            attributes.current.speed *= ENRAGE_MULTIPLIER;
+            attributes.current.damage *= ENRAGE_MULTIPLIER;
+        }
+    }
+}
+
+// ---------------- Bars & Labels ----------------
+pub fn update_shield_bars(zombies: Query<(&Shield, &Children)>, mut bars: Query<&mut Sprite, With<ShieldBar>>) {
+    for (shield, children) in zombies.iter() {
+        for &child in children.iter() {
+            if let Ok(mut sprite) = bars.get_mut(child) {
+                sprite.custom_size = Some(Vec2::new(25.0 * (shield.current / shield.max), 3.0));
+            }
+        }
+    }
+}
+
+pub fn keep_labels_upright(parents: Query<&Transform, Without<UprightLabel>>, mut labels: Query<(&Parent, &UprightLabel, &mut Transform)>) {
+    for (parent, label, mut t) in labels.iter_mut() {
+        if let Ok(parent_t) = parents.get(parent.get()) {
+            let inverse = parent_t.rotation.inverse();
+            t.rotation = inverse;
+            t.translation = inverse * label.offset;
+        }
+    }
+}
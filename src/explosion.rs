@@ -0,0 +1,154 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use crate::damage::{Damage, DamageEvent, DamageType, PlayerDamageEvent};
+use crate::gameover::Restart;
+use crate::player::Player;
+use crate::projectile::{spawn_projectile, InflictStatus, Lobbed, OnExpire, Projectile};
+use crate::spatial::SpatialGrid;
+use crate::status::{ApplyStatus, StatusKind};
+use crate::steering::Steering;
+use crate::zombie::Blast;
+
+// ---------------- Constants ----------------
+pub const EDGE_DAMAGE: f32 = 0.4;      // fraction of damage dealt at the edge of the radius
+pub const EXPLOSION_PUSH: f32 = 250.0;
+pub const GRENADE_KEY: KeyCode = KeyCode::G;
+pub const GRENADE_COOLDOWN: f32 = 4.0;
+pub const GRENADE_FUSE: f32 = 1.6;
+pub const GRENADE_FLIGHT: f32 = 0.8;   // time in the air before it lands and sits on its fuse
+pub const GRENADE_DISTANCE: f32 = 260.0;
+pub const GRENADE_ARC_SCALE: f32 = 1.5; // extra sprite scale at the top of the arc
+pub const GRENADE_RADIUS: f32 = 110.0;
+pub const GRENADE_DAMAGE: f32 = 90.0;
+pub const SELF_DAMAGE: f32 = 0.25;     // fraction of a player-made explosion that hurts the player
+pub const GRENADE_POISON: f32 = 4.0;   // seconds of poison from the grenade's gas
+
+// ---------------- Events ----------------
+// Area damage with linear falloff from the centre; `source` is passed through to every `DamageEvent`
+#[derive(Event)]
+pub struct Explosion {
+    pub position: Vec3,
+    pub radius: f32,
+    pub damage: f32,
+    pub player_damage: f32, // at the centre; zero for explosions that spare the player
+    pub source: Option<usize>,
+    pub status: Option<(StatusKind, f32)>, // left on every zombie caught in the blast
+}
+
+// ---------------- Components ----------------
+// Flies as a `Projectile`; this only drives the arc and the blinking fuse
+#[derive(Component)]
+pub struct Grenade;
+
+#[derive(Resource)]
+pub struct GrenadeCooldown(pub Timer);
+
+impl Default for GrenadeCooldown {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(GRENADE_COOLDOWN, TimerMode::Once);
+        timer.tick(timer.duration());
+        Self(timer)
+    }
+}
+
+// ---------------- Explosions ----------------
+#[allow(clippy::too_many_arguments)]
+pub fn apply_explosions(
+    mut commands: Commands,
+    mut explosions: EventReader<Explosion>,
+    asset_server: Res<AssetServer>,
+    grid: Res<SpatialGrid>,
+    player: Query<&Transform, With<Player>>,
+    mut steering: Query<&mut Steering>,
+    mut damage: EventWriter<DamageEvent>,
+    mut player_damage: EventWriter<PlayerDamageEvent>,
+    mut statuses: EventWriter<ApplyStatus>,
+) {
+    for explosion in explosions.read() {
+        let center = explosion.position.truncate();
+        let falloff = |distance: f32| 1.0 - (1.0 - EDGE_DAMAGE) * (distance / explosion.radius).min(1.0);
+
+        for (target, pos) in grid.query_radius(center, explosion.radius) {
+            let factor = falloff(pos.distance(center));
+            damage.send(DamageEvent { target, amount: explosion.damage * factor, kind: DamageType::Explosive, source: explosion.source, crit: false, direct: false });
+            if let Some((kind, duration)) = explosion.status {
+                statuses.send(ApplyStatus { target, kind, duration, source: explosion.source });
+            }
+            if let Ok(mut steering) = steering.get_mut(target) {
+                steering.velocity += (pos - center).normalize_or_zero() * EXPLOSION_PUSH * factor;
+            }
+        }
+
+        if explosion.player_damage > 0.0 {
+            for t in player.iter() {
+                let distance = t.translation.truncate().distance(center);
+                if distance < explosion.radius {
+                    player_damage.send(PlayerDamageEvent { amount: explosion.player_damage * falloff(distance) });
+                }
+            }
+        }
+
+        // Flash plus a bright core
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(center.extend(2.0)),
+            sprite: Sprite { color: Color::rgba(1.0, 0.5, 0.1, 0.6), custom_size: Some(Vec2::splat(explosion.radius * 2.0)), ..default() },
+            ..default()
+        }).insert(Blast { timer: Timer::from_seconds(0.25, TimerMode::Once), alpha: 0.6 });
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(center.extend(2.1)),
+            sprite: Sprite { color: Color::rgba(1.0, 0.95, 0.6, 0.9), custom_size: Some(Vec2::splat(explosion.radius * 0.8)), ..default() },
+            ..default()
+        }).insert(Blast { timer: Timer::from_seconds(0.12, TimerMode::Once), alpha: 0.9 });
+
+        // Deep boom
+        commands.spawn(AudioBundle {
+            source: asset_server.load("audio/explosion.ogg"),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(0.8)),
+        });
+    }
+}
+
+// ---------------- Grenades ----------------
+pub fn throw_grenade(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    mut cooldown: ResMut<GrenadeCooldown>,
+    player: Query<&Transform, With<Player>>,
+) {
+    cooldown.0.tick(time.delta());
+    if !keyboard.just_pressed(GRENADE_KEY) || !cooldown.0.finished() { return; }
+    let Ok(t) = player.get_single() else { return };
+    cooldown.0.reset();
+
+    // Thrown hard and slowed to a stop on landing, then it sits on the rest of its fuse
+    let speed = 2.0 * GRENADE_DISTANCE / GRENADE_FLIGHT;
+    let projectile = Projectile {
+        acceleration: Vec2::new(0.0, -speed / GRENADE_FLIGHT),
+        on_expire: OnExpire::Explode(GRENADE_RADIUS),
+        ..Projectile::new(Vec2::new(0.0, speed), f32::INFINITY, GRENADE_FUSE)
+    };
+    let damage = Damage { amount: GRENADE_DAMAGE, kind: DamageType::Explosive, crit_chance: 0.0, crit_multiplier: 1.0, source: None, direct: false };
+    spawn_projectile(&mut commands, t.translation + Vec3::new(0.0, 20.0, 1.0), projectile, damage, Vec2::splat(8.0), Color::DARK_GREEN)
+        .insert((Grenade, Lobbed, InflictStatus(StatusKind::Poisoned, GRENADE_POISON)));
+}
+
+pub fn animate_grenades(mut grenades: Query<(&Projectile, &mut Transform, &mut Sprite), With<Grenade>>) {
+    for (projectile, mut t, mut sprite) in grenades.iter_mut() {
+        let fuse = &projectile.lifetime;
+        // Top-down arc: the sprite grows towards the peak and shrinks as it lands
+        let p = (fuse.elapsed_secs() / GRENADE_FLIGHT).min(1.0);
+        t.scale = Vec3::splat(1.0 + GRENADE_ARC_SCALE * 4.0 * p * (1.0 - p));
+
+        // Blink faster as the fuse runs down
+        let blink = (fuse.elapsed_secs() * (4.0 + 20.0 * fuse.percent())).sin() > 0.0;
+        sprite.color = if blink { Color::RED } else { Color::DARK_GREEN };
+    }
+}
+
+// Grenades in flight are bullets, so the restart clears them with the rest
+pub fn reset_grenades(mut restarts: EventReader<Restart>, mut cooldown: ResMut<GrenadeCooldown>) {
+    for _ in restarts.read() {
+        *cooldown = GrenadeCooldown::default();
+    }
+}
@@ -1,14 +1,11 @@
 use bevy::prelude::*;
 use crate::zombie::Zombie;
-use crate::player::Bullet;
-use crate::time::SurvivalTime;
-use crate::zombie::{
-    ZombieStats,
-    ZombieSpawnTimer,
-    INITIAL_ZOMBIE_SPEED,
-    INITIAL_SPAWN_INTERVAL,
-    INITIAL_ZOMBIE_HEALTH,
-};
+use crate::player::{Bullet, PlayerHealth};
+use crate::zombie::SpitProjectile;
+use crate::wave::GameMode;
+use crate::score::{Coins, Score};
+use crate::stats::RunStats;
+use crate::weapons::Weapons;
 
 
 #[derive(Resource)]
@@ -20,9 +17,35 @@ pub struct GameOverUI;
 #[derive(Component)]
 pub struct RestartButton;
 
+#[derive(Component)]
+pub struct ModeButton;
+
+// Sent once when the player restarts; each module resets its own state on it
+#[derive(Event)]
+pub struct Restart;
+
+fn mode_label(mode: GameMode) -> String {
+    match mode {
+        GameMode::Waves => "Mode: Waves".to_string(),
+        GameMode::Endless => "Mode: Endless".to_string(),
+    }
+}
+
 // Spawn Game Over UI
-pub fn show_game_over(mut commands: Commands, game_over: Res<GameOver>, query: Query<Entity, With<GameOverUI>>) {
+#[allow(clippy::too_many_arguments)]
+pub fn show_game_over(mut commands: Commands, game_over: Res<GameOver>, mode: Res<GameMode>, run_stats: Res<RunStats>,
+                      weapons: Res<Weapons>, score: Res<Score>, coins: Res<Coins>, query: Query<Entity, With<GameOverUI>>) {
     if game_over.0 && query.is_empty() {
+        // One line per weapon that landed a hit this run
+        let summary = run_stats.weapons.iter().enumerate()
+            .filter(|(_, s)| s.hits > 0)
+            .map(|(i, s)| {
+                let name = weapons.stats.get(i).map_or("Unknown", |w| w.name);
+                format!("{}: {} hits ({} crits), {} kills, {:.0} damage", name, s.hits, s.crits, s.kills, s.damage)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
         commands.spawn((NodeBundle {
             style: Style {
                 width: Val::Percent(100.0),
@@ -40,6 +63,17 @@ pub fn show_game_over(mut commands: Commands, game_over: Res<GameOver>, query: Q
                 text: Text::from_section("Game Over!", TextStyle { font: Default::default(), font_size: 60.0, color: Color::WHITE }),
                 ..default()
             });
+            parent.spawn(TextBundle {
+                text: Text::from_sections([
+                    TextSection::new(format!("Score: {}   ", score.0), TextStyle { font: Default::default(), font_size: 30.0, color: Color::WHITE }),
+                    TextSection::new(format!("Coins: {}", coins.0), TextStyle { font: Default::default(), font_size: 30.0, color: Color::GOLD }),
+                ]),
+                ..default()
+            });
+            parent.spawn(TextBundle {
+                text: Text::from_section(summary, TextStyle { font: Default::default(), font_size: 20.0, color: Color::WHITE }),
+                ..default()
+            });
             parent.spawn(ButtonBundle {
                 style: Style {
                     width: Val::Px(150.0),
@@ -59,40 +93,48 @@ pub fn show_game_over(mut commands: Commands, game_over: Res<GameOver>, query: Q
                     ..default()
                 });
             });
+            parent.spawn(ButtonBundle {
+                style: Style {
+                    width: Val::Px(220.0),
+                    height: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::DARK_GRAY.into(),
+                ..default()
+            })
+            .insert(ModeButton)
+            .with_children(|b| {
+                b.spawn(TextBundle {
+                    text: Text::from_section(mode_label(*mode), TextStyle { font: Default::default(), font_size: 24.0, color: Color::WHITE }),
+                    ..default()
+                });
+            });
         });
     }
 }
 
 // Restart game when button clicked
+#[allow(clippy::too_many_arguments)]
 pub fn restart_game(
     mut commands: Commands,
     mut interaction_query: Query<(&Interaction, &mut BackgroundColor), With<RestartButton>>,
     mut game_over: ResMut<GameOver>,
-    mut survival_time: ResMut<SurvivalTime>,
+    mut restarts: EventWriter<Restart>,
     zombies: Query<Entity, With<Zombie>>,
     bullets: Query<Entity, With<Bullet>>,
+    spit: Query<Entity, With<SpitProjectile>>,
     ui_elements: Query<Entity, With<GameOverUI>>,
-    mut zombie_stats: ResMut<ZombieStats>,
-    mut zombie_spawn_timer: ResMut<ZombieSpawnTimer>,
 ) {
     for (interaction, _) in &mut interaction_query {
         if *interaction == Interaction::Pressed {
-            // Reset flags
+            // Reset flags; difficulty, timers and waves reset on the Restart event
             game_over.0 = false;
-            survival_time.0 = 0.0;
-
-            // Reset difficulty
-            zombie_stats.speed = INITIAL_ZOMBIE_SPEED;
-            zombie_stats.spawn_interval = INITIAL_SPAWN_INTERVAL;
-            zombie_stats.health = INITIAL_ZOMBIE_HEALTH;
-            zombie_stats.ramp_timer.reset();
-
-            // Reset spawn timer
-            zombie_spawn_timer.0.set_duration(std::time::Duration::from_secs_f32(INITIAL_SPAWN_INTERVAL));
-            zombie_spawn_timer.0.reset();
+            restarts.send(Restart);
 
             // Despawn everything
-            for e in zombies.iter().chain(bullets.iter()).chain(ui_elements.iter()) {
+            for e in zombies.iter().chain(bullets.iter()).chain(spit.iter()).chain(ui_elements.iter()) {
                 commands.entity(e).despawn_recursive();
             }
         }
@@ -100,9 +142,36 @@ pub fn restart_game(
 }
 
 
+// Toggle between wave and endless mode; takes effect on restart
+#[allow(clippy::type_complexity)]
+pub fn toggle_game_mode(
+    interaction_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<ModeButton>)>,
+    mut texts: Query<&mut Text>,
+    mut mode: ResMut<GameMode>,
+) {
+    for (interaction, children) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            *mode = match *mode {
+                GameMode::Waves => GameMode::Endless,
+                GameMode::Endless => GameMode::Waves,
+            };
+            for &child in children.iter() {
+                if let Ok(mut text) = texts.get_mut(child) {
+                    text.sections[0].value = mode_label(*mode);
+                }
+            }
+        }
+    }
+}
+
 // Check if any zombie reached bottom
 pub fn check_zombie_bottom(mut game_over: ResMut<GameOver>, query: Query<&Transform, With<Zombie>>) {
     for t in query.iter() {
         if t.translation.y < -300.0 { game_over.0 = true; }
     }
 }
+
+// Game over when the player runs out of health
+pub fn check_player_dead(mut game_over: ResMut<GameOver>, health: Res<PlayerHealth>) {
+    if health.current <= 0.0 && !game_over.0 { game_over.0 = true; }
+}
@@ -0,0 +1,187 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use crate::damage::{is_head_hit, Damage, DamageEvent};
+use crate::player::Player;
+use crate::spatial::SpatialGrid;
+use crate::status::{ApplyStatus, StatusKind};
+use crate::weapons::{FireMode, Weapons};
+use crate::zombie::{Blast, Zombie};
+
+// ---------------- Constants ----------------
+pub const RAY_HALF_WIDTH: f32 = 3.0;
+pub const TRACER_TIME: f32 = 0.15;
+pub const BEAM_WIDTH: f32 = 4.0;
+pub const CHAIN_RADIUS: f32 = 120.0;
+pub const ARC_TIME: f32 = 0.2;
+
+// ---------------- Events ----------------
+// An instant shot straight up from `origin`; it hits the `pierce + 1` nearest zombies on the line,
+// or every zombie in range when `pierce` is None
+#[derive(Event)]
+pub struct HitscanShot {
+    pub origin: Vec3,
+    pub damage: Damage,
+    pub range: f32,
+    pub pierce: Option<u32>,
+    pub tracer: Option<Color>,
+    pub status: Option<(StatusKind, f32)>,
+}
+
+// Lightning that arcs to the nearest zombie within `range`, then jumps to the nearest unhit zombie
+// within `CHAIN_RADIUS` up to `jumps` times, losing `decay` of its damage each jump
+#[derive(Event)]
+pub struct ChainShot {
+    pub origin: Vec3,
+    pub damage: Damage,
+    pub range: f32,
+    pub jumps: u32,
+    pub decay: f32,
+    pub status: Option<(StatusKind, f32)>,
+}
+
+// ---------------- Components ----------------
+#[derive(Component)] pub struct LaserBeam;
+
+// ---------------- Systems ----------------
+pub fn resolve_hitscan(
+    mut commands: Commands,
+    mut shots: EventReader<HitscanShot>,
+    zombies: Query<(Entity, &Transform, &Zombie)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut statuses: EventWriter<ApplyStatus>,
+) {
+    let mut rng = rand::rng();
+    for shot in shots.read() {
+        let origin = shot.origin.truncate();
+
+        // Zombies overlapping the ray, nearest first, and whether it went through the head
+        let mut hits: Vec<(Entity, f32, bool)> = zombies.iter()
+            .filter_map(|(e, t, z)| {
+                let offset = t.translation.truncate() - origin;
+                let size = z.kind.profile().size;
+                let on_line = offset.x.abs() < size * 0.5 + RAY_HALF_WIDTH;
+                let headshot = is_head_hit(origin, Vec2::Y, t, size);
+                (on_line && offset.y > 0.0 && offset.y < shot.range).then_some((e, offset.y, headshot))
+            })
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some(pierce) = shot.pierce {
+            hits.truncate(pierce as usize + 1);
+        }
+
+        for &(target, _, headshot) in &hits {
+            damage_events.send(shot.damage.hit(target, headshot, &mut rng));
+            if let Some((kind, duration)) = shot.status {
+                statuses.send(ApplyStatus { target, kind, duration, source: shot.damage.source });
+            }
+        }
+
+        // Tracer stops at the last zombie it went through
+        if let Some(color) = shot.tracer {
+            let length = match shot.pierce {
+                Some(pierce) if hits.len() > pierce as usize => hits.last().map_or(shot.range, |h| h.1),
+                _ => shot.range,
+            };
+            commands.spawn(SpriteBundle {
+                transform: Transform::from_translation((origin + Vec2::new(0.0, length / 2.0)).extend(1.5)),
+                sprite: Sprite { color, custom_size: Some(Vec2::new(2.0, length)), ..default() },
+                ..default()
+            }).insert(Blast { timer: Timer::from_seconds(TRACER_TIME, TimerMode::Once), alpha: color.a() });
+        }
+    }
+}
+
+pub fn resolve_chain(
+    mut commands: Commands,
+    mut shots: EventReader<ChainShot>,
+    grid: Res<SpatialGrid>,
+    zombies: Query<(Entity, &Transform), With<Zombie>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut statuses: EventWriter<ApplyStatus>,
+) {
+    let mut rng = rand::rng();
+    for shot in shots.read() {
+        let mut from = shot.origin.truncate();
+        let mut damage = shot.damage;
+        let mut hit: Vec<Entity> = Vec::new();
+
+        // First target is the nearest zombie to the muzzle
+        let mut next = zombies.iter()
+            .map(|(e, t)| (e, t.translation.truncate()))
+            .filter(|(_, pos)| pos.distance(from) < shot.range)
+            .min_by(|a, b| a.1.distance(from).total_cmp(&b.1.distance(from)));
+
+        while let Some((target, pos)) = next {
+            damage_events.send(damage.hit(target, false, &mut rng));
+            if let Some((kind, duration)) = shot.status {
+                statuses.send(ApplyStatus { target, kind, duration, source: damage.source });
+            }
+            spawn_arc(&mut commands, from, pos);
+            hit.push(target);
+            if hit.len() > shot.jumps as usize { break; }
+
+            from = pos;
+            damage.amount *= 1.0 - shot.decay;
+            next = grid.query_radius(from, CHAIN_RADIUS).into_iter()
+                .filter(|(e, _)| !hit.contains(e))
+                .min_by(|a, b| a.1.distance(from).total_cmp(&b.1.distance(from)));
+        }
+    }
+}
+
+// A thin bolt between two points
+fn spawn_arc(commands: &mut Commands, from: Vec2, to: Vec2) {
+    let delta = to - from;
+    commands.spawn(SpriteBundle {
+        transform: Transform {
+            translation: ((from + to) / 2.0).extend(1.5),
+            rotation: Quat::from_rotation_z(delta.y.atan2(delta.x)),
+            ..default()
+        },
+        sprite: Sprite { color: Color::rgb(0.7, 0.9, 1.0), custom_size: Some(Vec2::new(delta.length(), 2.0)), ..default() },
+        ..default()
+    }).insert(Blast { timer: Timer::from_seconds(ARC_TIME, TimerMode::Once), alpha: 1.0 });
+}
+
+// Keeps a beam drawn from the muzzle while a beam weapon is firing
+pub fn update_laser_beam(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    weapons: Res<Weapons>,
+    player: Query<&Transform, (With<Player>, Without<LaserBeam>)>,
+    mut beams: Query<(Entity, &mut Transform, &mut Sprite), With<LaserBeam>>,
+) {
+    let stats = &weapons.stats[weapons.active];
+    let firing = stats.fire_mode == FireMode::Beam
+        && keyboard.pressed(KeyCode::Space)
+        && weapons.ammo[weapons.active].loaded > 0
+        && !weapons.is_reloading();
+    let Ok(player_t) = player.get_single() else { return };
+
+    if !firing {
+        for (e, _, _) in beams.iter() {
+            commands.entity(e).despawn();
+        }
+        return;
+    }
+
+    let center = player_t.translation + Vec3::new(0., stats.feel.barrel + stats.range / 2., 1.5);
+    // Slight flicker so the beam reads as energy
+    let width = BEAM_WIDTH * (1.0 + 0.3 * (time.elapsed_seconds() * 40.0).sin());
+    if let Ok((_, mut t, mut sprite)) = beams.get_single_mut() {
+        t.translation = center;
+        sprite.custom_size = Some(Vec2::new(width, stats.range));
+    } else {
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(center),
+            sprite: Sprite { color: stats.color, custom_size: Some(Vec2::new(width, stats.range)), ..default() },
+            ..default()
+        }).insert(AudioBundle {
+            // Low looping hum for as long as the beam exists
+            source: asset_server.load("audio/laser_hum.ogg"),
+            settings: PlaybackSettings::LOOP.with_volume(Volume::new_relative(0.3)),
+        }).insert(LaserBeam);
+    }
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use crate::player::Bullet;
+use crate::projectile::{heading, Projectile};
+use crate::zombie::Zombie;
+
+// ---------------- Constants ----------------
+pub const TURN_RATE: f32 = 4.0;        // radians per second
+pub const ACQUIRE_RADIUS: f32 = 450.0;
+
+// ---------------- Components ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TargetMode {
+    Nearest,
+    LowestHealth, // finishes off wounded zombies first
+}
+
+#[derive(Component)]
+pub struct Homing { pub mode: TargetMode, pub target: Option<Entity> }
+
+impl Homing {
+    pub fn new(mode: TargetMode) -> Self {
+        Self { mode, target: None }
+    }
+}
+
+// ---------------- Systems ----------------
+// Runs before `projectile::move_projectiles`, which expires missiles at the end of their lifetime
+pub fn steer_homing(
+    time: Res<Time>,
+    mut missiles: Query<(&mut Homing, &mut Projectile, &mut Transform), With<Bullet>>,
+    zombies: Query<(Entity, &Transform, &Zombie), Without<Bullet>>,
+) {
+    let dt = time.delta_seconds();
+    for (mut homing, mut projectile, mut t) in missiles.iter_mut() {
+        // Retarget once the current target is gone
+        let pos = t.translation.truncate();
+        let alive = homing.target.is_some_and(|target| zombies.get(target).is_ok_and(|(_, _, z)| z.health > 0.0));
+        if !alive {
+            let candidates = zombies.iter()
+                .filter(|(_, zt, z)| z.health > 0.0 && zt.translation.truncate().distance(pos) < ACQUIRE_RADIUS);
+            homing.target = match homing.mode {
+                TargetMode::Nearest => candidates
+                    .min_by(|a, b| a.1.translation.truncate().distance(pos).total_cmp(&b.1.translation.truncate().distance(pos))),
+                TargetMode::LowestHealth => candidates.min_by(|a, b| a.2.health.total_cmp(&b.2.health)),
+            }.map(|(target, _, _)| target);
+        }
+
+        // Turn towards the target by at most `TURN_RATE` this frame
+        if let Some(target) = homing.target && let Ok((_, target_t, _)) = zombies.get(target) {
+            let desired = (target_t.translation.truncate() - pos).normalize_or_zero();
+            let current = projectile.velocity.normalize_or_zero();
+            let angle = current.angle_between(desired).clamp(-TURN_RATE * dt, TURN_RATE * dt);
+            projectile.velocity = Vec2::from_angle(angle).rotate(projectile.velocity);
+        }
+        t.rotation = heading(projectile.velocity);
+    }
+}
@@ -1,11 +1,31 @@
 use bevy::prelude::*;
 use bevy::audio::{AudioBundle, PlaybackSettings, Volume};
 
+mod alt_fire;
+mod aura;
+mod boss;
+mod combo;
+mod damage;
+mod elite;
+mod explosion;
 mod player;
+mod powerup;
+mod projectile;
+mod recoil;
 mod zombie;
 mod gameover;
+mod hitscan;
+mod homing;
+mod melee;
+mod minigun;
 mod score;
+mod spatial;
+mod spawn_pattern;
+mod stats;
+mod status;
+mod steering;
 mod time;
+mod wave;
 mod weapons; // renamed from abilities
 
 fn setup_camera(mut commands: Commands) {
@@ -40,48 +60,180 @@ fn main() {
         .add_systems(Startup, setup_background_music)
         .add_systems(Startup, player::setup_player)
         .add_systems(Startup, player::setup_weapon)
+        .add_systems(Startup, player::setup_health_ui)
         .add_systems(Startup, zombie::setup_zombie_stats)
         .add_systems(Startup, time::setup_ui)
         .add_systems(Startup, score::setup_score_ui)
         .add_systems(Startup, weapons::setup_weapons) // weapons system
+        .add_systems(Startup, alt_fire::setup_alt_fire_ui)
+        .add_systems(Startup, powerup::setup_buff_ui)
+        .add_systems(Startup, combo::setup_combo_ui)
+        .add_systems(Startup, wave::setup_wave_ui)
+        .add_systems(Startup, boss::setup_boss_ui)
 
         // Resources
         .insert_resource(gameover::GameOver(false))
         .insert_resource(time::SurvivalTime(0.0))
         .insert_resource(score::Score(0))
+        .insert_resource(score::Coins(0))
+        .insert_resource(player::PlayerHealth { current: player::PLAYER_MAX_HEALTH, max: player::PLAYER_MAX_HEALTH })
+        .insert_resource(wave::GameMode::Waves)
+        .init_resource::<wave::WaveDirector>()
+        .init_resource::<spatial::SpatialGrid>()
+        .init_resource::<stats::RunStats>()
+        .init_resource::<minigun::MinigunState>()
+        .init_resource::<explosion::GrenadeCooldown>()
+        .init_resource::<melee::MeleeCooldown>()
+        .init_resource::<alt_fire::AltFireState>()
+        .init_resource::<recoil::RecoilState>()
+        .init_resource::<powerup::Buffs>()
+        .init_resource::<combo::Combo>()
+        .add_event::<gameover::Restart>()
+        .add_event::<damage::DamageEvent>()
+        .add_event::<damage::ZombieKilled>()
+        .add_event::<damage::PlayerDamageEvent>()
+        .add_event::<explosion::Explosion>()
+        .add_event::<hitscan::HitscanShot>()
+        .add_event::<hitscan::ChainShot>()
+        .add_event::<status::ApplyStatus>()
+        .add_event::<recoil::ShotFired>()
         .insert_resource(zombie::ZombieSpawnTimer(Timer::from_seconds(
             zombie::INITIAL_SPAWN_INTERVAL,
             TimerMode::Repeating,
         )))
+        .insert_resource(boss::EndlessBossTimer(Timer::from_seconds(
+            boss::ENDLESS_BOSS_INTERVAL,
+            TimerMode::Repeating,
+        )))
+
+        // Per-zombie attributes are rebuilt, then modified, before anything reads them
+        .configure_sets(Update, (zombie::AttributeSet::Reset, zombie::AttributeSet::Modify).chain())
 
         // Player systems
         .add_systems(Update, player::player_movement)
         .add_systems(Update, player::shooting)
-        .add_systems(Update, player::move_bullets)
-        .add_systems(Update, player::bullet_hit_zombie)
+        .add_systems(Update, projectile::move_projectiles)
+        .add_systems(Update, homing::steer_homing.before(projectile::move_projectiles))
+        .add_systems(Update, projectile::projectile_hit_zombie)
+        .add_systems(Update, projectile::bullet_hit_spit)
         .add_systems(Update, player::update_weapon_sprite)
+        .add_systems(Update, player::update_health_ui)
+        .add_systems(Update, player::reset_player_health)
+
+        // Damage systems
+        .add_systems(Update, damage::apply_zombie_damage.after(zombie::AttributeSet::Modify))
+        .add_systems(Update, damage::apply_player_damage)
+
+        // Explosion systems
+        .add_systems(Update, explosion::apply_explosions.after(spatial::rebuild_zombie_grid))
+        .add_systems(Update, explosion::throw_grenade)
+        .add_systems(Update, explosion::animate_grenades)
+        .add_systems(Update, explosion::reset_grenades)
+
+        // Hitscan systems
+        .add_systems(Update, hitscan::resolve_hitscan)
+        .add_systems(Update, hitscan::update_laser_beam)
+        .add_systems(Update, hitscan::resolve_chain.after(spatial::rebuild_zombie_grid))
+
+        // Melee systems
+        .add_systems(Update, melee::melee_attack)
+        .add_systems(Update, melee::reset_melee)
+
+        // Alt-fire systems
+        .add_systems(Update, alt_fire::alt_fire.after(weapons::reload_weapons))
+        .add_systems(Update, alt_fire::update_alt_fire_ui)
+        .add_systems(Update, alt_fire::reset_alt_fire)
+
+        // Recoil systems
+        .add_systems(Update, recoil::apply_shot_feel.after(player::shooting).after(alt_fire::alt_fire))
+        .add_systems(Update, recoil::recover_recoil.after(recoil::apply_shot_feel))
+        .add_systems(Update, recoil::update_casings)
+        .add_systems(Update, recoil::reset_recoil)
+
+        // Power-up systems
+        .add_systems(Update, powerup::drop_powerups)
+        .add_systems(Update, powerup::update_powerups)
+        .add_systems(Update, powerup::collect_powerups.after(powerup::update_powerups))
+        .add_systems(Update, powerup::tick_buffs)
+        .add_systems(Update, powerup::update_buff_ui)
+        .add_systems(Update, powerup::reset_powerups)
+
+        // Status effect systems
+        .add_systems(Update, status::apply_status_events)
+        .add_systems(Update, status::tick_statuses)
+        .add_systems(Update, status::apply_status_modifiers.in_set(zombie::AttributeSet::Modify))
+        .add_systems(Update, status::update_status_visuals)
 
         // Zombie systems
         .add_systems(Update, zombie::ramp_zombie_difficulty)
         .add_systems(Update, zombie::spawn_zombies)
-        .add_systems(Update, zombie::move_zombies)
+        .add_systems(Update, spatial::rebuild_zombie_grid)
+        .add_systems(Update, zombie::reset_zombie_attributes.in_set(zombie::AttributeSet::Reset))
+        .add_systems(Update, steering::steer_zombies.after(spatial::rebuild_zombie_grid).after(zombie::AttributeSet::Modify))
+        .add_systems(Update, zombie::zombie_contact_damage.after(zombie::AttributeSet::Modify))
         .add_systems(Update, zombie::update_healthbars)
         .add_systems(Update, zombie::animate_zombies)
+        .add_systems(Update, zombie::reset_zombie_stats)
+        .add_systems(Update, zombie::spitter_attack)
+        .add_systems(Update, zombie::move_spit)
+        .add_systems(Update, zombie::explode_on_death)
+        // Splitlings must exist before the wave director checks whether the wave is cleared
+        .add_systems(Update, (zombie::split_on_death, apply_deferred).chain().after(damage::apply_zombie_damage).before(wave::advance_waves))
+        .add_systems(Update, zombie::summon_minions)
+        .add_systems(Update, zombie::fade_blasts)
+        .add_systems(Update, aura::tick_auras.after(spatial::rebuild_zombie_grid))
+
+        // Elite systems
+        .add_systems(Update, elite::enrage_elites.in_set(zombie::AttributeSet::Modify))
+        .add_systems(Update, elite::regenerate_elites)
+        .add_systems(Update, elite::vampiric_drain.after(zombie::AttributeSet::Modify))
+        .add_systems(Update, elite::update_shield_bars)
+        .add_systems(Update, elite::keep_labels_upright)
+
+        // Boss systems
+        .add_systems(Update, boss::spawn_endless_boss)
+        .add_systems(Update, boss::boss_behaviour.after(zombie::AttributeSet::Modify))
+        .add_systems(Update, boss::update_boss_bar)
+        .add_systems(Update, boss::reset_boss_timer)
+
+        // Spawn pattern systems
+        .add_systems(Update, spawn_pattern::hatch_spawn_warnings)
+        .add_systems(Update, spawn_pattern::clear_spawn_warnings)
+
+        // Wave systems
+        .add_systems(Update, wave::advance_waves)
+        .add_systems(Update, wave::reset_waves)
+        .add_systems(Update, wave::update_wave_ui)
 
         // Timer
         .add_systems(Update, time::update_survival_time)
+        .add_systems(Update, time::reset_survival_time)
 
         // Game systems
         .add_systems(Update, gameover::check_zombie_bottom)
+        .add_systems(Update, gameover::check_player_dead)
         .add_systems(Update, gameover::show_game_over)
         .add_systems(Update, gameover::restart_game)
+        .add_systems(Update, gameover::toggle_game_mode)
 
         // Score systems
         .add_systems(Update, score::update_floating_scores)
         .add_systems(Update, score::update_score_ui)
+        .add_systems(Update, score::reset_score)
+        .add_systems(Update, combo::update_combo)
+        .add_systems(Update, combo::update_combo_ui)
+        .add_systems(Update, combo::reset_combo)
+        .add_systems(Update, stats::reset_run_stats)
 
         // Weapons input & UI
         .add_systems(Update, weapons::handle_weapon_input)
         .add_systems(Update, weapons::update_weapon_ui)
+        .add_systems(Update, weapons::reload_weapons.before(player::shooting))
+        .add_systems(Update, weapons::update_ammo_ui)
+        .add_systems(Update, weapons::update_shop_panel)
+        .add_systems(Update, minigun::update_minigun.before(player::shooting))
+        .add_systems(Update, minigun::update_heat_bar)
+        .add_systems(Update, minigun::reset_minigun)
+        .add_systems(Update, weapons::reset_ammo)
         .run();
 }
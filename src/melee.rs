@@ -0,0 +1,76 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use crate::damage::{DamageEvent, DamageType};
+use crate::gameover::Restart;
+use crate::player::Player;
+use crate::steering::Steering;
+use crate::zombie::{Blast, Zombie};
+
+// ---------------- Constants ----------------
+pub const MELEE_KEY: KeyCode = KeyCode::F;
+pub const MELEE_DAMAGE: f32 = 50.0;
+pub const MELEE_RANGE: f32 = 60.0;
+pub const MELEE_ARC: f32 = 1.1;          // half-angle of the swing either side of straight up, radians
+pub const MELEE_COOLDOWN: f32 = 0.6;
+pub const MELEE_KNOCKBACK: f32 = 700.0;
+pub const MELEE_KILL_BONUS: u32 = 25;
+
+// ---------------- Resources ----------------
+#[derive(Resource)]
+pub struct MeleeCooldown(pub Timer);
+
+impl Default for MeleeCooldown {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(MELEE_COOLDOWN, TimerMode::Once);
+        timer.tick(timer.duration());
+        Self(timer)
+    }
+}
+
+// ---------------- Systems ----------------
+#[allow(clippy::too_many_arguments)]
+pub fn melee_attack(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut cooldown: ResMut<MeleeCooldown>,
+    player: Query<&Transform, With<Player>>,
+    mut zombies: Query<(Entity, &Transform, &Zombie, Option<&mut Steering>), Without<Player>>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    cooldown.0.tick(time.delta());
+    if !keyboard.just_pressed(MELEE_KEY) || !cooldown.0.finished() { return; }
+    let Ok(player_t) = player.get_single() else { return };
+    cooldown.0.reset();
+    let origin = player_t.translation.truncate();
+
+    // Arc hitbox in front of the player; reach grows with the zombie's size
+    for (e, t, z, steering) in zombies.iter_mut() {
+        let offset = t.translation.truncate() - origin;
+        let size = z.kind.profile().size;
+        if offset.length() > MELEE_RANGE + size * 0.5 || offset.angle_between(Vec2::Y).abs() > MELEE_ARC { continue; }
+
+        damage.send(DamageEvent { target: e, amount: MELEE_DAMAGE, kind: DamageType::Melee, source: None, crit: false, direct: true });
+        if let Some(mut steering) = steering {
+            steering.velocity += offset.normalize_or_zero() * MELEE_KNOCKBACK * 20.0 / size;
+        }
+    }
+
+    // Swipe visual and a quick whoosh
+    commands.spawn(SpriteBundle {
+        transform: Transform::from_translation((origin + Vec2::new(0.0, MELEE_RANGE * 0.6)).extend(1.5)),
+        sprite: Sprite { color: Color::rgba(1.0, 1.0, 1.0, 0.6), custom_size: Some(Vec2::new(MELEE_RANGE * 2.0 * MELEE_ARC.sin(), 8.0)), ..default() },
+        ..default()
+    }).insert(Blast { timer: Timer::from_seconds(0.12, TimerMode::Once), alpha: 0.6 });
+    commands.spawn(AudioBundle {
+        source: asset_server.load("audio/melee_whoosh.ogg"),
+        settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(0.5)),
+    });
+}
+
+pub fn reset_melee(mut restarts: EventReader<Restart>, mut cooldown: ResMut<MeleeCooldown>) {
+    for _ in restarts.read() {
+        *cooldown = MeleeCooldown::default();
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use crate::gameover::Restart;
+use crate::weapons::{self, Weapons};
+
+// ---------------- Constants ----------------
+pub const SPIN_UP_TIME: f32 = 1.2;
+pub const SPIN_DOWN_TIME: f32 = 0.8;
+pub const STALLED_FIRE_FACTOR: f32 = 5.0; // fire interval multiplier at zero spin
+pub const HEAT_PER_SHOT: f32 = 0.012;
+pub const COOL_PER_SECOND: f32 = 0.35;
+pub const OVERHEAT_LOCKOUT: f32 = 2.5;
+pub const HEAT_BAR_WIDTH: f32 = 40.0;
+
+// ---------------- Resources ----------------
+#[derive(Resource, Default)]
+pub struct MinigunState {
+    pub spin: f32,             // 0 = stopped, 1 = full speed
+    pub heat: f32,             // overheats at 1
+    pub lockout: Option<Timer>,
+}
+
+impl MinigunState {
+    pub fn overheated(&self) -> bool { self.lockout.is_some() }
+
+    // Fire interval ramps from slow to the weapon's own rate as the barrels spin up
+    pub fn fire_interval(&self, base: f32) -> f32 {
+        base * (STALLED_FIRE_FACTOR + (1.0 - STALLED_FIRE_FACTOR) * self.spin)
+    }
+
+    pub fn add_heat(&mut self) {
+        self.heat += HEAT_PER_SHOT;
+        if self.heat >= 1.0 {
+            self.heat = 1.0;
+            self.lockout = Some(Timer::from_seconds(OVERHEAT_LOCKOUT, TimerMode::Once));
+            println!("Minigun overheated!");
+        }
+    }
+}
+
+// ---------------- Components ----------------
+#[derive(Component)] pub struct HeatBar;
+#[derive(Component)] pub struct SpinSound;
+
+// ---------------- Systems ----------------
+pub fn update_minigun(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    weapons: Res<Weapons>,
+    mut state: ResMut<MinigunState>,
+    sounds: Query<(Entity, Option<&AudioSink>), With<SpinSound>>,
+) {
+    let dt = time.delta_seconds();
+    let spinning = weapons.active == weapons::MINIGUN && keyboard.pressed(KeyCode::Space) && !state.overheated();
+
+    if spinning {
+        state.spin = (state.spin + dt / SPIN_UP_TIME).min(1.0);
+    } else {
+        state.spin = (state.spin - dt / SPIN_DOWN_TIME).max(0.0);
+        state.heat = (state.heat - COOL_PER_SECOND * dt).max(0.0);
+    }
+
+    if let Some(timer) = state.lockout.as_mut() && timer.tick(time.delta()).finished() {
+        state.lockout = None;
+    }
+
+    // Looping motor whirr whose pitch follows the spin, reaching its natural pitch at full spin
+    if sounds.is_empty() {
+        if state.spin > 0.0 {
+            commands.spawn(AudioBundle {
+                source: asset_server.load("audio/minigun_spin.ogg"),
+                settings: PlaybackSettings::LOOP.with_speed(0.4).with_volume(Volume::new_relative(0.4)),
+            }).insert(SpinSound);
+        }
+    } else {
+        for (e, sink) in sounds.iter() {
+            if state.spin <= 0.0 {
+                commands.entity(e).despawn();
+            } else if let Some(sink) = sink {
+                sink.set_speed(0.4 + 0.6 * state.spin);
+            }
+        }
+    }
+}
+
+pub fn update_heat_bar(state: Res<MinigunState>, time: Res<Time>, mut bars: Query<(&mut Style, &mut BackgroundColor), With<HeatBar>>) {
+    for (mut style, mut color) in bars.iter_mut() {
+        style.width = Val::Px(HEAT_BAR_WIDTH * state.heat);
+        color.0 = if state.overheated() {
+            // Flash while locked out
+            if (time.elapsed_seconds() * 8.0).sin() > 0.0 { Color::RED } else { Color::WHITE }
+        } else {
+            Color::rgb(1.0, 0.65 * (1.0 - state.heat), 0.0)
+        };
+    }
+}
+
+pub fn reset_minigun(mut restarts: EventReader<Restart>, mut state: ResMut<MinigunState>) {
+    for _ in restarts.read() {
+        *state = MinigunState::default();
+    }
+}
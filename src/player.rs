@@ -1,35 +1,32 @@
+use std::time::Duration;
+use bevy::audio::Volume;
 use bevy::prelude::*;
+use crate::{damage::DamageType, gameover::Restart, homing::{Homing, TargetMode}, hitscan::{ChainShot, HitscanShot}, minigun::MinigunState, powerup::{Buffs, PowerUpKind, RAPID_FIRE}, recoil::{RecoilState, ShotFired, WEAPON_REST_Y}, projectile::{spawn_projectile, InflictStatus, Knockback}, weapons::{self, FireMode, Weapons}};
 use rand::Rng;
-use crate::{zombie::Zombie, score::{Score, FloatingScore}, weapons::Weapons};
 
 pub const PLAYER_SPEED: f32 = 500.;
 pub const BULLET_SPEED: f32 = 800.;
+pub const PLAYER_MAX_HEALTH: f32 = 100.;
 
 #[derive(Component)] pub struct Player;
 #[derive(Component)] pub struct Bullet;
-#[derive(Resource)] pub struct Weapon { pub is_minigun: bool, pub fire_timer: Timer }
+#[derive(Component)] pub struct HealthText;
+#[derive(Resource)] pub struct PlayerHealth { pub current: f32, pub max: f32 }
+// Per-weapon fire-rate cooldowns, indexed like `Weapons`
+#[derive(Resource)] pub struct Weapon { pub cooldowns: Vec<Timer> }
 
+// Held-weapon images, indexed like `Weapons`
 #[derive(Component)]
 pub struct WeaponSprite {
-    pub pistol: Handle<Image>,
-    pub shotgun: Handle<Image>,
-    pub minigun: Handle<Image>,
+    pub images: Vec<Handle<Image>>,
 }
 
-#[derive(Component)]
-pub struct ShotgunBullet {
-    pub direction: Vec3,
-}
-
-
 pub fn setup_player(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
 ) {
     // Load weapon images
-    let pistol_handle = asset_server.load("images/pistol.png");
-    let shotgun_handle = asset_server.load("images/shotgun.png");
-    let minigun_handle = asset_server.load("images/minigun.png");
+    let images: Vec<Handle<Image>> = weapons::WEAPON_IMAGES.iter().map(|path| asset_server.load(*path)).collect();
 
     commands.spawn(SpriteBundle {
         transform: Transform::from_xyz(0., -250., 0.),
@@ -39,20 +36,15 @@ pub fn setup_player(
     .insert(Player)
     .with_children(|parent| {
         parent.spawn(SpriteBundle {
-    texture: pistol_handle.clone(),
+    texture: images[weapons::PISTOL].clone(),
     transform: Transform {
-        translation: Vec3::new(0., 20., 1.),
+        translation: Vec3::new(0., WEAPON_REST_Y, 1.),
         rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
         scale: Vec3::splat(5.0),
-        ..default()
     },
     ..default()
 })
-.insert(WeaponSprite {
-    pistol: pistol_handle,
-    shotgun: shotgun_handle,
-    minigun: minigun_handle,
-});
+.insert(WeaponSprite { images });
     });
 }
 
@@ -62,31 +54,22 @@ pub fn update_weapon_sprite(
     mut query: Query<(&mut Handle<Image>, &mut Sprite, &WeaponSprite)>
 ) {
     for (mut texture, mut sprite, weapon_sprite) in &mut query {
-        match weapons.active {
-            0 => { // pistol
-                *texture = weapon_sprite.pistol.clone();
-                sprite.custom_size = Some(Vec2::new(6., 6.));
-            }
-            1 => { // shotgun
-                *texture = weapon_sprite.shotgun.clone();
-                sprite.custom_size = Some(Vec2::new(8., 8.));
-            }
-            2 => { // minigun
-                *texture = weapon_sprite.minigun.clone();
-                sprite.custom_size = Some(Vec2::new(8., 8.));
-            }
-            _ => {}
-        }
+        *texture = weapon_sprite.images[weapons.active].clone();
+        let size = if weapons.active == weapons::PISTOL { 6. } else { 8. };
+        sprite.custom_size = Some(Vec2::new(size, size));
     }
 }
 
 
 
 pub fn setup_weapon(mut commands: Commands) {
-    commands.insert_resource(Weapon {
-        is_minigun: false,
-        fire_timer: Timer::from_seconds(0.05, TimerMode::Repeating),
-    });
+    let cooldowns = weapons::base_weapon_stats().iter().map(|stats| {
+        // Start ready to fire
+        let mut timer = Timer::from_seconds(stats.fire_interval, TimerMode::Once);
+        timer.tick(timer.duration());
+        timer
+    }).collect();
+    commands.insert_resource(Weapon { cooldowns });
 }
 
 pub fn player_movement(keyboard: Res<Input<KeyCode>>, mut query: Query<&mut Transform, With<Player>>, time: Res<Time>) {
@@ -97,103 +80,137 @@ pub fn player_movement(keyboard: Res<Input<KeyCode>>, mut query: Query<&mut Tran
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn shooting(
     keyboard: Res<Input<KeyCode>>,
     mut commands: Commands,
     query: Query<&Transform, With<Player>>,
     asset_server: Res<AssetServer>,
     time: Res<Time>,
-    weapons: Res<Weapons>,
+    mut weapons: ResMut<Weapons>,
     mut weapon: ResMut<Weapon>,
+    mut minigun: ResMut<MinigunState>,
+    mut hitscan: EventWriter<HitscanShot>,
+    mut chain: EventWriter<ChainShot>,
+    recoil: Res<RecoilState>,
+    buffs: Res<Buffs>,
+    mut shots: EventWriter<ShotFired>,
 ) {
-    // Update weapon type
-    weapon.is_minigun = weapons.active == 2; // minigun index
-    let is_shotgun = weapons.active == 1;    // shotgun index
-
-    // Determine if shooting
-    let shoot = if weapon.is_minigun {
-        weapon.fire_timer.tick(time.delta());
-        keyboard.pressed(KeyCode::Space) && weapon.fire_timer.finished()
+    for cooldown in weapon.cooldowns.iter_mut() {
+        cooldown.tick(time.delta());
+    }
+
+    let active = weapons.active;
+    let trigger = if weapons.stats[active].automatic { keyboard.pressed(KeyCode::Space) } else { keyboard.just_pressed(KeyCode::Space) };
+    if !trigger || !weapon.cooldowns[active].finished() { return; }
+    if active == weapons::MINIGUN && minigun.overheated() { return; }
+
+    // Dry fire: a short, quiet click
+    if weapons.ammo[active].loaded == 0 || weapons.is_reloading() {
+        if keyboard.just_pressed(KeyCode::Space) {
+            commands.spawn(AudioBundle {
+                source: asset_server.load("audio/dry_fire.ogg"),
+                settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(0.5)),
+            });
+        }
+        return;
+    }
+    weapons.ammo[active].loaded -= 1;
+    let stats = &weapons.stats[active];
+    // Upgrades change the fire rate, and the minigun ramps up to it
+    let mut interval = if active == weapons::MINIGUN {
+        minigun.add_heat();
+        minigun.fire_interval(stats.fire_interval)
     } else {
-        keyboard.just_pressed(KeyCode::Space)
+        stats.fire_interval
     };
-
-    if shoot {
-        for transform in &query {
-            if is_shotgun {
-                // Spawn multiple bullets with spread
-                let angles: [f32; 5] = [-0.2, -0.1, 0.0, 0.1, 0.2]; // radians
-                for &angle in &angles {
-                    let t = transform.translation;
-                    let dir = Vec3::new(angle.sin(), 1.0, 0.0).normalize();
-                    commands.spawn(SpriteBundle {
-                        transform: Transform::from_translation(t + Vec3::new(0., 30., 0.)),
-                        sprite: Sprite { color: Color::ORANGE, custom_size: Some(Vec2::new(3., 7.)), ..default() },
-                        ..default()
-                    })
-                    .insert(Bullet)
-                    .insert(ShotgunBullet { direction: dir });
-                }
-            } else {
-                // Regular bullet (pistol or minigun)
-                commands.spawn(SpriteBundle {
-                    transform: Transform::from_xyz(transform.translation.x, transform.translation.y + 30., 0.),
-                    sprite: Sprite { color: Color::YELLOW, custom_size: Some(Vec2::new(3., 7.)), ..default() },
-                    ..default()
-                }).insert(Bullet);
+    if buffs.has(PowerUpKind::RapidFire) { interval *= RAPID_FIRE; }
+    weapon.cooldowns[active].set_duration(Duration::from_secs_f32(interval));
+    weapon.cooldowns[active].reset();
+
+    let damage = stats.damage_for(active);
+
+    for transform in &query {
+        let origin = transform.translation + Vec3::new(0., stats.feel.barrel, 0.);
+        shots.send(ShotFired { weapon: active, origin });
+        if stats.fire_mode == FireMode::Chain {
+            chain.send(ChainShot { origin, damage, range: stats.range, jumps: stats.chain_jumps, decay: stats.chain_decay, status: stats.status });
+            commands.spawn(AudioBundle {
+                source: asset_server.load("audio/bullet.ogg"),
+                settings: PlaybackSettings::DESPAWN.with_speed(1.8),
+            });
+            continue;
+        }
+        if stats.fire_mode != FireMode::Projectile {
+            let beam = stats.fire_mode == FireMode::Beam;
+            hitscan.send(HitscanShot {
+                origin,
+                damage,
+                range: stats.range,
+                pierce: (!beam).then_some(stats.pierce),
+                tracer: (!beam).then_some(stats.color),
+                status: stats.status,
+            });
+            // The beam hums instead of playing a shot per tick
+            if !beam {
+                commands.spawn(AudioBundle {
+                    source: asset_server.load("audio/bullet.ogg"),
+                    settings: PlaybackSettings::DESPAWN.with_speed(0.7),
+                });
             }
-
-            // Play shooting sound
-            let sound = asset_server.load("audio/bullet.ogg");
-            commands.spawn(AudioBundle { source: sound, settings: PlaybackSettings::default(), ..default() });
+            continue;
         }
-    }
-}
 
-pub fn move_bullets(
-    mut commands: Commands,
-    mut query: Query<(Entity, &mut Transform, Option<&ShotgunBullet>), With<Bullet>>,
-    time: Res<Time>
-) {
-    for (e, mut t, shotgun) in &mut query {
-        let dir = if let Some(s) = shotgun {
-            s.direction
-        } else {
-            Vec3::Y
-        };
-        t.translation += dir * BULLET_SPEED * time.delta_seconds();
-        if t.translation.y > 300. || t.translation.x.abs() > 400. {
-            commands.entity(e).despawn();
+        let mut rng = rand::rng();
+        for pellet in 0..stats.pellets {
+            // Scatter pellets randomly across the cone, widened by bloom from sustained fire
+            let cone = stats.spread / 2. + recoil.bloom(active);
+            let angle = if cone > 0. { rng.random_range(-cone..=cone) } else { 0. };
+            let mut bullet = spawn_projectile(&mut commands, origin, stats.projectile(Vec2::new(angle.sin(), angle.cos())), damage, Vec2::new(3., 7.), stats.color);
+            if stats.knockback > 0. {
+                bullet.insert(Knockback(stats.knockback));
+            }
+            if let Some((kind, duration)) = stats.status {
+                bullet.insert(InflictStatus(kind, duration));
+            }
+            if stats.damage_type == DamageType::Fire {
+                bullet.insert(Sprite { color: stats.color, custom_size: Some(Vec2::splat(6.)), ..default() });
+            }
+            if stats.blast_radius > 0. {
+                bullet.insert(Sprite { color: stats.color, custom_size: Some(Vec2::new(6., 12.)), ..default() });
+            }
+            if stats.fire_mode == FireMode::Homing {
+                let mode = if pellet % 2 == 0 { TargetMode::Nearest } else { TargetMode::LowestHealth };
+                bullet.insert(Homing::new(mode))
+                    .insert(Sprite { color: stats.color, custom_size: Some(Vec2::new(4., 10.)), ..default() });
+            }
         }
+
+        // Play shooting sound
+        let sound = asset_server.load("audio/bullet.ogg");
+        commands.spawn(AudioBundle { source: sound, settings: PlaybackSettings::default() });
     }
 }
 
+pub fn setup_health_ui(mut commands: Commands) {
+    commands.spawn(TextBundle {
+        text: Text::from_section(
+            format!("HP: {}", PLAYER_MAX_HEALTH),
+            TextStyle { font: Default::default(), font_size: 30.0, color: Color::GREEN }
+        ),
+        style: Style { position_type: PositionType::Absolute, left: Val::Px(340.0), bottom: Val::Px(10.0), ..default() },
+        ..default()
+    }).insert(HealthText);
+}
 
-pub fn bullet_hit_zombie(mut commands: Commands, bullet_query: Query<(Entity, &Transform), With<Bullet>>,
-                         mut zombie_query: Query<(Entity, &Transform, &mut Zombie)>, mut score: ResMut<Score>) {
-    let mut rng = rand::thread_rng();
-    for (b_e, b_t) in &bullet_query {
-        for (z_e, z_t, mut z) in &mut zombie_query {
-            if b_t.translation.distance(z_t.translation) < 25. {
-                commands.entity(b_e).despawn();
-                z.health -= 25.;
-                score.0 += 10;
-                spawn_floating_score(&mut commands, z_t.translation, "+10", Color::YELLOW, &mut rng);
-                if z.health <= 0. {
-                    commands.entity(z_e).despawn_recursive();
-                    score.0 += 90;
-                    spawn_floating_score(&mut commands, z_t.translation, "+100", Color::GOLD, &mut rng);
-                }
-            }
-        }
+pub fn update_health_ui(health: Res<PlayerHealth>, mut query: Query<&mut Text, With<HealthText>>) {
+    for mut text in query.iter_mut() {
+        text.sections[0].value = format!("HP: {:.0}", health.current.max(0.));
     }
 }
 
-fn spawn_floating_score(commands: &mut Commands, pos: Vec3, text: &str, color: Color, rng: &mut impl Rng) {
-    let offset = Vec3::new(rng.random_range(-10.0..10.0), rng.random_range(10.0..25.0), 1.);
-    commands.spawn(Text2dBundle {
-        text: Text::from_section(text, TextStyle { font: Default::default(), font_size: 20., color }),
-        transform: Transform::from_translation(pos + offset),
-        ..default()
-    }).insert(FloatingScore { timer: Timer::from_seconds(0.5, TimerMode::Once) });
+pub fn reset_player_health(mut restarts: EventReader<Restart>, mut health: ResMut<PlayerHealth>) {
+    for _ in restarts.read() {
+        health.current = health.max;
+    }
 }
@@ -0,0 +1,357 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::damage::{DamageEvent, DamageType, ZombieKilled};
+use crate::gameover::Restart;
+use crate::player::{Player, PlayerHealth};
+use crate::score::{Coins, spawn_floating_score};
+use crate::status::{ApplyStatus, StatusKind};
+use crate::weapons::Weapons;
+use crate::elite::Shield;
+use crate::projectile::{ARENA_HALF_HEIGHT, ARENA_HALF_WIDTH};
+use crate::zombie::{Blast, Zombie, ZombieAttributes, ZombieKind};
+
+// ---------------- Constants ----------------
+pub const POWERUP_LIFETIME: f32 = 10.0;
+pub const POWERUP_BLINK: f32 = 3.0;     // blinks for the last few seconds before it expires
+pub const POWERUP_SIZE: f32 = 14.0;
+pub const PICKUP_RADIUS: f32 = 20.0;
+pub const MAGNET_RADIUS: f32 = 120.0;   // magnetic pickups drift to the player from this far
+pub const MAGNET_SPEED: f32 = 260.0;
+pub const DROP_POP: f32 = 90.0;         // initial hop away from the corpse
+pub const DROP_DRAG: f32 = 5.0;
+pub const DROP_FALL: f32 = 90.0;        // drift down into the player's row, in units per second
+pub const HEALTH_PICKUP: f32 = 25.0;
+pub const COIN_PICKUP: u32 = 50;
+pub const NUKE_BOSS_FRACTION: f32 = 0.2; // bosses lose this share of their max health instead of dying
+pub const FREEZE_DURATION: f32 = 4.0;
+pub const DOUBLE_DAMAGE: f32 = 2.0;
+pub const RAPID_FIRE: f32 = 0.5;         // fire interval multiplier
+
+// ---------------- Kinds ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PowerUpKind { Health, Ammo, Coins, DoubleDamage, RapidFire, Nuke, Freeze, Shield }
+
+impl PowerUpKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerUpKind::Health => "Health",
+            PowerUpKind::Ammo => "Ammo",
+            PowerUpKind::Coins => "Coins",
+            PowerUpKind::DoubleDamage => "Double Damage",
+            PowerUpKind::RapidFire => "Rapid Fire",
+            PowerUpKind::Nuke => "Nuke",
+            PowerUpKind::Freeze => "Freeze",
+            PowerUpKind::Shield => "Shield",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            PowerUpKind::Health => Color::GREEN,
+            PowerUpKind::Ammo => Color::rgb(0.8, 0.7, 0.3),
+            PowerUpKind::Coins => Color::GOLD,
+            PowerUpKind::DoubleDamage => Color::RED,
+            PowerUpKind::RapidFire => Color::ORANGE,
+            PowerUpKind::Nuke => Color::WHITE,
+            PowerUpKind::Freeze => Color::CYAN,
+            PowerUpKind::Shield => Color::rgb(0.4, 0.6, 1.0),
+        }
+    }
+
+    // Timed buffs last this long; instant pickups return None
+    pub fn duration(self) -> Option<f32> {
+        match self {
+            PowerUpKind::DoubleDamage => Some(10.0),
+            PowerUpKind::RapidFire => Some(8.0),
+            PowerUpKind::Shield => Some(6.0),
+            _ => None,
+        }
+    }
+
+    // Resources drift to the player; buffs have to be walked over
+    pub fn magnetic(self) -> bool {
+        matches!(self, PowerUpKind::Health | PowerUpKind::Ammo | PowerUpKind::Coins)
+    }
+}
+
+// Drop chance and weighted pickups per archetype
+pub fn loot_table(kind: ZombieKind) -> (f32, &'static [(PowerUpKind, u32)]) {
+    use PowerUpKind::*;
+    const COMMON: &[(PowerUpKind, u32)] = &[(Coins, 6), (Ammo, 4), (Health, 3), (RapidFire, 1), (DoubleDamage, 1)];
+    match kind {
+        ZombieKind::Walker | ZombieKind::Runner | ZombieKind::Exploder => (0.08, COMMON),
+        ZombieKind::Splitling | ZombieKind::Minion => (0.03, &[(Coins, 1)]),
+        ZombieKind::Tank => (0.35, &[(Ammo, 3), (Shield, 2), (DoubleDamage, 2), (Coins, 3)]),
+        ZombieKind::Splitter | ZombieKind::Spitter => (0.15, &[(Coins, 4), (Ammo, 3), (Freeze, 1), (RapidFire, 2)]),
+        ZombieKind::Healer => (0.3, &[(Health, 5), (Shield, 2), (Coins, 2)]),
+        ZombieKind::Summoner => (0.3, &[(Nuke, 1), (Freeze, 2), (Coins, 3), (DoubleDamage, 2)]),
+        ZombieKind::Boss => (1.0, &[(Nuke, 2), (Shield, 2), (DoubleDamage, 2), (RapidFire, 2)]),
+    }
+}
+
+fn roll_loot(kind: ZombieKind, rng: &mut impl Rng) -> Option<PowerUpKind> {
+    let (chance, table) = loot_table(kind);
+    if !rng.random_bool(chance as f64) { return None; }
+    let mut roll = rng.random_range(0..table.iter().map(|(_, weight)| weight).sum::<u32>());
+    table.iter().find(|(_, weight)| {
+        if roll < *weight { return true; }
+        roll -= weight;
+        false
+    }).map(|(kind, _)| *kind)
+}
+
+// ---------------- Components ----------------
+#[derive(Component)]
+pub struct PowerUp { pub kind: PowerUpKind, pub velocity: Vec2, pub lifetime: Timer }
+
+#[derive(Component)]
+pub struct BuffText;
+
+// ---------------- Resources ----------------
+// Timed buffs currently running; picking up a running buff refreshes it
+#[derive(Resource, Default)]
+pub struct Buffs { pub active: Vec<(PowerUpKind, Timer)> }
+
+impl Buffs {
+    pub fn has(&self, kind: PowerUpKind) -> bool {
+        self.active.iter().any(|(k, _)| *k == kind)
+    }
+
+    fn add(&mut self, kind: PowerUpKind, duration: f32) {
+        self.active.retain(|(k, _)| *k != kind);
+        self.active.push((kind, Timer::from_seconds(duration, TimerMode::Once)));
+    }
+}
+
+// ---------------- Setup ----------------
+pub fn setup_buff_ui(mut commands: Commands) {
+    commands.spawn(TextBundle {
+        text: Text::default(),
+        style: Style { position_type: PositionType::Absolute, left: Val::Px(80.0), top: Val::Px(10.0), ..default() },
+        ..default()
+    }).insert(BuffText);
+}
+
+// ---------------- Systems ----------------
+pub fn drop_powerups(mut commands: Commands, mut killed: EventReader<ZombieKilled>) {
+    let mut rng = rand::rng();
+    for event in killed.read() {
+        let Some(kind) = roll_loot(event.kind, &mut rng) else { continue };
+        let angle = rng.random_range(0.0..std::f32::consts::TAU);
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(event.position.truncate().extend(0.4)).with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            sprite: Sprite { color: kind.color(), custom_size: Some(Vec2::splat(POWERUP_SIZE)), ..default() },
+            ..default()
+        }).insert(PowerUp {
+            kind,
+            velocity: Vec2::from_angle(angle) * DROP_POP,
+            lifetime: Timer::from_seconds(POWERUP_LIFETIME, TimerMode::Once),
+        });
+    }
+}
+
+impl PowerUp {
+    // Moves a pickup at `pos` for one frame and returns where it ends up. It hops out of the corpse
+    // and then drifts down into the player's row, which is the only place the player can reach.
+    // Resources are also pulled in once the player is close.
+    pub fn step(&mut self, pos: Vec2, player: Option<Vec2>, dt: f32) -> Vec2 {
+        if self.kind.magnetic() && let Some(p) = player && p.distance(pos) < MAGNET_RADIUS {
+            self.velocity = (p - pos).normalize_or_zero() * MAGNET_SPEED;
+        } else {
+            self.velocity *= (1.0 - DROP_DRAG * dt).max(0.0);
+        }
+        let mut next = pos + self.velocity * dt;
+        if let Some(p) = player {
+            next.y -= (next.y - p.y).clamp(0.0, DROP_FALL * dt);
+        }
+        next
+    }
+}
+
+// Pickups bob in place and blink before they vanish
+pub fn update_powerups(
+    mut commands: Commands,
+    time: Res<Time>,
+    player: Query<&Transform, (With<Player>, Without<PowerUp>)>,
+    mut powerups: Query<(Entity, &mut PowerUp, &mut Transform, &mut Sprite)>,
+) {
+    let dt = time.delta_seconds();
+    let player_pos = player.get_single().map(|t| t.translation.truncate()).ok();
+    for (e, mut powerup, mut t, mut sprite) in powerups.iter_mut() {
+        if powerup.lifetime.tick(time.delta()).finished() {
+            commands.entity(e).despawn();
+            continue;
+        }
+        let pos = powerup.step(t.translation.truncate(), player_pos, dt);
+        t.translation = pos.extend(t.translation.z);
+        t.scale = Vec3::splat(1.0 + 0.15 * (powerup.lifetime.elapsed_secs() * 5.0).sin());
+
+        let remaining = powerup.lifetime.remaining_secs();
+        let visible = remaining > POWERUP_BLINK || (remaining * 8.0).sin() > 0.0;
+        sprite.color.set_a(if visible { 1.0 } else { 0.15 });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn collect_powerups(
+    mut commands: Commands,
+    player: Query<&Transform, With<Player>>,
+    powerups: Query<(Entity, &PowerUp, &Transform), Without<Player>>,
+    zombies: Query<(Entity, &Transform, &Zombie, &ZombieAttributes, Option<&Shield>)>,
+    mut health: ResMut<PlayerHealth>,
+    mut weapons: ResMut<Weapons>,
+    mut coins: ResMut<Coins>,
+    mut buffs: ResMut<Buffs>,
+    mut damage: EventWriter<DamageEvent>,
+    mut statuses: EventWriter<ApplyStatus>,
+) {
+    let Ok(player_t) = player.get_single() else { return };
+    let mut rng = rand::rng();
+    for (e, powerup, t) in powerups.iter() {
+        if t.translation.truncate().distance(player_t.translation.truncate()) > PICKUP_RADIUS { continue; }
+        commands.entity(e).despawn();
+        spawn_floating_score(&mut commands, t.translation, powerup.kind.label(), powerup.kind.color(), &mut rng);
+
+        match powerup.kind {
+            PowerUpKind::Health => health.current = (health.current + HEALTH_PICKUP).min(health.max),
+            PowerUpKind::Ammo => {
+                // One magazine's worth of reserve for every weapon owned
+                let weapons = &mut *weapons;
+                for (i, ammo) in weapons.ammo.iter_mut().enumerate() {
+                    let stats = &weapons.stats[i];
+                    if let (true, Some(reserve), Some(max)) = (weapons.purchased[i], ammo.reserve, stats.max_reserve) {
+                        ammo.reserve = Some((reserve + stats.magazine).min(max));
+                    }
+                }
+            }
+            PowerUpKind::Coins => coins.0 += COIN_PICKUP,
+            PowerUpKind::Nuke => {
+                // Only what's on screen; zombies still walking in from above are spared
+                let on_screen = |t: &Transform| t.translation.x.abs() <= ARENA_HALF_WIDTH && t.translation.y.abs() <= ARENA_HALF_HEIGHT;
+                for (z_e, z_t, z, attributes, shield) in zombies.iter() {
+                    if !on_screen(z_t) { continue; }
+                    let amount = if z.kind == ZombieKind::Boss {
+                        z.max_health * NUKE_BOSS_FRACTION
+                    } else {
+                        // Just enough to get through shield and armour
+                        let armour = attributes.current.armour.clamp(0., 0.9) * DamageType::Explosive.armour_factor();
+                        z.health / (1. - armour) + shield.map_or(0., |shield| shield.current)
+                    };
+                    damage.send(DamageEvent { target: z_e, amount, kind: DamageType::Explosive, source: None, crit: false, direct: false });
+                }
+                commands.spawn(SpriteBundle {
+                    transform: Transform::from_xyz(0.0, 0.0, 5.0),
+                    sprite: Sprite { color: Color::rgba(1.0, 1.0, 1.0, 0.8), custom_size: Some(Vec2::new(800.0, 600.0)), ..default() },
+                    ..default()
+                }).insert(Blast { timer: Timer::from_seconds(0.4, TimerMode::Once), alpha: 0.8 });
+            }
+            PowerUpKind::Freeze => {
+                for (z_e, ..) in zombies.iter() {
+                    statuses.send(ApplyStatus { target: z_e, kind: StatusKind::Frozen, duration: FREEZE_DURATION, source: None });
+                }
+            }
+            kind => {
+                if let Some(duration) = kind.duration() { buffs.add(kind, duration); }
+            }
+        }
+    }
+}
+
+pub fn tick_buffs(time: Res<Time>, mut buffs: ResMut<Buffs>) {
+    for (_, timer) in buffs.active.iter_mut() {
+        timer.tick(time.delta());
+    }
+    buffs.active.retain(|(_, timer)| !timer.finished());
+}
+
+pub fn update_buff_ui(buffs: Res<Buffs>, mut query: Query<&mut Text, With<BuffText>>) {
+    for mut text in query.iter_mut() {
+        text.sections = buffs.active.iter().map(|(kind, timer)| {
+            TextSection::new(
+                format!("{} {:.1}s\n", kind.label(), timer.remaining_secs()),
+                TextStyle { font: Default::default(), font_size: 18.0, color: kind.color() },
+            )
+        }).collect();
+    }
+}
+
+pub fn reset_powerups(
+    mut commands: Commands,
+    mut restarts: EventReader<Restart>,
+    mut buffs: ResMut<Buffs>,
+    powerups: Query<Entity, With<PowerUp>>,
+) {
+    for _ in restarts.read() {
+        *buffs = Buffs::default();
+        for e in powerups.iter() {
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn buffs_dropped_up_field_reach_the_player() {
+        let player = Vec2::new(0.0, -250.0);
+        for kind in [PowerUpKind::DoubleDamage, PowerUpKind::RapidFire, PowerUpKind::Nuke, PowerUpKind::Freeze, PowerUpKind::Shield] {
+            let mut powerup = PowerUp { kind, velocity: Vec2::Y * DROP_POP, lifetime: Timer::from_seconds(POWERUP_LIFETIME, TimerMode::Once) };
+            let mut pos = Vec2::new(0.0, 280.0);
+            let dt = 1.0 / 60.0;
+            while !powerup.lifetime.tick(std::time::Duration::from_secs_f32(dt)).finished() {
+                pos = powerup.step(pos, Some(player), dt);
+                if pos.distance(player) <= PICKUP_RADIUS { break; }
+            }
+            assert!(pos.distance(player) <= PICKUP_RADIUS, "{kind:?} stopped at {pos}");
+            assert!(powerup.lifetime.remaining_secs() > POWERUP_BLINK, "{kind:?} arrived too late");
+        }
+    }
+
+    #[test]
+    fn drops_settle_in_the_player_row() {
+        let player = Vec2::new(0.0, -250.0);
+        let mut powerup = PowerUp { kind: PowerUpKind::Shield, velocity: Vec2::ZERO, lifetime: Timer::from_seconds(POWERUP_LIFETIME, TimerMode::Once) };
+        let mut pos = Vec2::new(200.0, 0.0);
+        for _ in 0..600 {
+            pos = powerup.step(pos, Some(player), 1.0 / 60.0);
+        }
+        assert_eq!(pos, Vec2::new(200.0, player.y));
+    }
+
+    #[test]
+    fn bosses_always_drop_from_their_table() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (_, table) = loot_table(ZombieKind::Boss);
+        for _ in 0..200 {
+            let kind = roll_loot(ZombieKind::Boss, &mut rng).expect("bosses always drop");
+            assert!(table.iter().any(|(entry, _)| *entry == kind));
+        }
+    }
+
+    #[test]
+    fn drops_follow_the_table_weights() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let (_, table) = loot_table(ZombieKind::Boss);
+        let total: u32 = table.iter().map(|(_, weight)| weight).sum();
+        let rolls = 10_000;
+        let drops: Vec<_> = (0..rolls).filter_map(|_| roll_loot(ZombieKind::Boss, &mut rng)).collect();
+        for (kind, weight) in table {
+            let share = drops.iter().filter(|drop| *drop == kind).count() as f32 / rolls as f32;
+            let expected = *weight as f32 / total as f32;
+            assert!((share - expected).abs() < 0.03, "{kind:?}: {share} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn drop_rate_matches_the_archetype_chance() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (chance, _) = loot_table(ZombieKind::Walker);
+        let rolls = 20_000;
+        let drops = (0..rolls).filter(|_| roll_loot(ZombieKind::Walker, &mut rng).is_some()).count();
+        assert!((drops as f32 / rolls as f32 - chance).abs() < 0.01);
+    }
+}
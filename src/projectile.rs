@@ -0,0 +1,215 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use crate::damage::{is_head_hit, Damage, DamageEvent, DamageType};
+use crate::explosion::Explosion;
+use crate::player::Bullet;
+use crate::status::{ApplyStatus, StatusKind};
+use crate::steering::Steering;
+use crate::zombie::{SpitProjectile, Zombie};
+
+// ---------------- Constants ----------------
+pub const ARENA_HALF_WIDTH: f32 = 400.;
+pub const ARENA_HALF_HEIGHT: f32 = 300.;
+pub const KNOCKBACK_RANGE: f32 = 120.;
+
+// ---------------- Components ----------------
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OnExpire {
+    Vanish,
+    Explode(f32), // blast radius; explosive projectiles also go off on the first zombie they touch
+}
+
+// Everything a bullet does in flight; weapons only differ in the numbers they put here
+#[derive(Component, Clone, Debug)]
+pub struct Projectile {
+    pub velocity: Vec2,      // world units per second
+    pub acceleration: Vec2,  // constant, e.g. a rocket's boost; applied every frame
+    pub lifetime: Timer,
+    pub distance: f32,       // covered so far
+    pub range: f32,
+    pub falloff: f32,        // damage lost by the end of the range
+    pub bounces: u32,        // ricochets off the arena walls left
+    pub pierce: u32,         // zombies it can still pass through
+    pub hit: Vec<Entity>,
+    pub on_expire: OnExpire,
+}
+
+impl Projectile {
+    pub fn new(velocity: Vec2, range: f32, lifetime: f32) -> Self {
+        Self {
+            velocity,
+            acceleration: Vec2::ZERO,
+            lifetime: Timer::from_seconds(lifetime, TimerMode::Once),
+            distance: 0.,
+            range,
+            falloff: 0.,
+            bounces: 0,
+            pierce: 0,
+            hit: Vec::new(),
+            on_expire: OnExpire::Vanish,
+        }
+    }
+
+    pub fn damage_factor(&self) -> f32 {
+        1. - self.falloff * (self.distance / self.range).min(1.)
+    }
+
+    fn expired(&self) -> bool {
+        self.lifetime.finished() || self.distance > self.range
+    }
+}
+
+// Status effect and duration applied to every zombie the bullet hits
+#[derive(Component)]
+pub struct InflictStatus(pub StatusKind, pub f32);
+
+// Pushes zombies back when they're hit within `KNOCKBACK_RANGE`, harder the closer they are
+#[derive(Component)]
+pub struct Knockback(pub f32);
+
+// Thrown over the horde: never touches zombies or spit, only goes off when it expires
+#[derive(Component)]
+pub struct Lobbed;
+
+// ---------------- Spawning ----------------
+// Callers add knockback, status or homing on top
+pub fn spawn_projectile<'w, 's, 'a>(
+    commands: &'a mut Commands<'w, 's>,
+    origin: Vec3,
+    projectile: Projectile,
+    damage: Damage,
+    size: Vec2,
+    color: Color,
+) -> EntityCommands<'w, 's, 'a> {
+    let mut bullet = commands.spawn(SpriteBundle {
+        // Long axis along the direction of travel
+        transform: Transform::from_translation(origin).with_rotation(heading(projectile.velocity)),
+        sprite: Sprite { color, custom_size: Some(size), ..default() },
+        ..default()
+    });
+    bullet.insert(Bullet).insert(damage).insert(projectile);
+    bullet
+}
+
+pub fn heading(velocity: Vec2) -> Quat {
+    Quat::from_rotation_z(-velocity.x.atan2(velocity.y))
+}
+
+// Explosive projectiles leave their status on everything caught in the blast
+fn detonate(damage: &Damage, status: Option<&InflictStatus>, position: Vec3, radius: f32) -> Explosion {
+    Explosion { status: status.map(|InflictStatus(kind, duration)| (*kind, *duration)), ..damage.explosion(position, radius) }
+}
+
+// ---------------- Systems ----------------
+#[allow(clippy::type_complexity)]
+pub fn move_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut Projectile, &mut Sprite, &Damage, Option<&InflictStatus>), With<Bullet>>,
+    mut explosions: EventWriter<Explosion>,
+) {
+    let dt = time.delta_seconds();
+    for (e, mut t, mut projectile, mut sprite, damage, status) in &mut query {
+        projectile.lifetime.tick(time.delta());
+        let (velocity, acceleration) = (projectile.velocity, projectile.acceleration);
+        projectile.velocity += acceleration * dt;
+        // Acceleration against the direction of travel only brakes: it stops, rather than turning back
+        if projectile.velocity.dot(velocity) < 0. {
+            projectile.velocity = Vec2::ZERO;
+            projectile.acceleration = Vec2::ZERO;
+        }
+        t.translation += projectile.velocity.extend(0.) * dt;
+        projectile.distance += projectile.velocity.length() * dt;
+
+        // Ricochet off the arena walls while bounces last
+        let mut out = false;
+        for (axis, limit) in [(0, ARENA_HALF_WIDTH), (1, ARENA_HALF_HEIGHT)] {
+            if t.translation[axis].abs() > limit {
+                if projectile.bounces == 0 {
+                    out = true;
+                } else {
+                    t.translation[axis] = limit.copysign(t.translation[axis]);
+                    projectile.velocity[axis] = -projectile.velocity[axis];
+                    projectile.bounces -= 1;
+                    t.rotation = heading(projectile.velocity);
+                }
+            }
+        }
+
+        // Flames billow out and burn away over their short range
+        if damage.kind == DamageType::Fire {
+            let progress = (projectile.distance / projectile.range).min(1.);
+            t.scale = Vec3::splat(1. + 2. * progress);
+            sprite.color.set_a(1. - progress);
+        }
+
+        if out || projectile.expired() {
+            if let OnExpire::Explode(radius) = projectile.on_expire {
+                explosions.send(detonate(damage, status, t.translation, radius));
+            }
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn projectile_hit_zombie(
+    mut commands: Commands,
+    mut bullets: Query<(Entity, &Transform, &Damage, &mut Projectile, Option<&Knockback>, Option<&InflictStatus>), (With<Bullet>, Without<Lobbed>)>,
+    mut zombies: Query<(Entity, &Transform, &Zombie, Option<&mut Steering>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut explosions: EventWriter<Explosion>,
+    mut statuses: EventWriter<ApplyStatus>,
+) {
+    let mut rng = rand::rng();
+    for (b_e, b_t, damage, mut projectile, knockback, status) in &mut bullets {
+        for (z_e, z_t, z, steering) in &mut zombies {
+            if projectile.hit.contains(&z_e) { continue; }
+            let size = z.kind.profile().size;
+            if b_t.translation.distance(z_t.translation) >= size { continue; }
+
+            // The blast covers the zombie that was hit
+            if let OnExpire::Explode(radius) = projectile.on_expire {
+                explosions.send(detonate(damage, status, b_t.translation, radius));
+                commands.entity(b_e).despawn();
+                break;
+            }
+            let mut hit = *damage;
+            hit.amount *= projectile.damage_factor();
+            let headshot = is_head_hit(b_t.translation.truncate(), projectile.velocity.normalize_or_zero(), z_t, size);
+            damage_events.send(hit.hit(z_e, headshot, &mut rng));
+            if let Some(InflictStatus(kind, duration)) = status {
+                statuses.send(ApplyStatus { target: z_e, kind: *kind, duration: *duration, source: damage.source });
+            }
+
+            // Bigger zombies are harder to shove; bosses don't steer and aren't moved at all
+            if let (Some(Knockback(force)), Some(mut steering)) = (knockback, steering) && projectile.distance < KNOCKBACK_RANGE {
+                let push = (z_t.translation - b_t.translation).truncate().normalize_or_zero() + Vec2::Y;
+                let strength = force * (1. - projectile.distance / KNOCKBACK_RANGE) * 20. / size;
+                steering.velocity += push.normalize_or_zero() * strength;
+            }
+
+            if projectile.pierce == 0 {
+                commands.entity(b_e).despawn();
+                break;
+            }
+            projectile.pierce -= 1;
+            projectile.hit.push(z_e);
+        }
+    }
+}
+
+// Spitter projectiles can be shot down
+#[allow(clippy::type_complexity)]
+pub fn bullet_hit_spit(mut commands: Commands, bullet_query: Query<(Entity, &Transform), (With<Bullet>, Without<Lobbed>)>,
+                       spit_query: Query<(Entity, &Transform), With<SpitProjectile>>) {
+    for (b_e, b_t) in &bullet_query {
+        for (s_e, s_t) in &spit_query {
+            if b_t.translation.distance(s_t.translation) < 10. {
+                commands.entity(b_e).despawn();
+                commands.entity(s_e).despawn();
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::gameover::Restart;
+use crate::player::WeaponSprite;
+use crate::weapons::Weapons;
+use crate::zombie::Blast;
+
+// ---------------- Constants ----------------
+pub const WEAPON_REST_Y: f32 = 20.;      // weapon sprite offset above the player
+pub const RECOIL_RECOVERY: f32 = 18.;    // fraction of the kick recovered per second, exponential
+pub const FLASH_TIME: f32 = 0.05;
+pub const CASING_LIFETIME: f32 = 0.8;
+pub const CASING_SPEED: f32 = 140.;
+pub const CASING_FRICTION: f32 = 4.;     // casings slide to a stop on the ground
+
+// ---------------- Events ----------------
+// Sent once per shot (not per pellet) by `player::shooting` and `alt_fire::alt_fire`
+#[derive(Event)]
+pub struct ShotFired { pub weapon: usize, pub origin: Vec3 }
+
+// ---------------- Resources ----------------
+// Current spread bloom per weapon, indexed like `Weapons`, and the weapon sprite's kick
+#[derive(Resource, Default)]
+pub struct RecoilState {
+    pub bloom: Vec<f32>,
+    pub kick: f32,
+}
+
+impl RecoilState {
+    pub fn bloom(&self, index: usize) -> f32 {
+        self.bloom.get(index).copied().unwrap_or(0.)
+    }
+}
+
+// ---------------- Components ----------------
+#[derive(Component)]
+pub struct ShellCasing { pub velocity: Vec2, pub spin: f32, pub timer: Timer }
+
+// ---------------- Systems ----------------
+pub fn apply_shot_feel(
+    mut commands: Commands,
+    mut shots: EventReader<ShotFired>,
+    weapons: Res<Weapons>,
+    mut state: ResMut<RecoilState>,
+) {
+    let mut rng = rand::rng();
+    for shot in shots.read() {
+        let feel = weapons.stats[shot.weapon].feel;
+        if state.bloom.len() <= shot.weapon {
+            state.bloom.resize(shot.weapon + 1, 0.);
+        }
+        state.bloom[shot.weapon] = (state.bloom[shot.weapon] + feel.bloom).min(feel.max_bloom);
+        state.kick = state.kick.max(feel.recoil);
+
+        if feel.flash > 0. {
+            commands.spawn(SpriteBundle {
+                transform: Transform::from_translation(shot.origin + Vec3::Z)
+                    .with_rotation(Quat::from_rotation_z(rng.random_range(0. ..std::f32::consts::TAU))),
+                sprite: Sprite { color: Color::rgba(1., 0.9, 0.5, 0.9), custom_size: Some(Vec2::splat(feel.flash)), ..default() },
+                ..default()
+            }).insert(Blast { timer: Timer::from_seconds(FLASH_TIME, TimerMode::Once), alpha: 0.9 });
+        }
+
+        // Brass flies out to the right and tumbles
+        for _ in 0..feel.casings {
+            let angle = rng.random_range(-0.5..0.5_f32);
+            let eject = shot.origin - Vec3::new(0., feel.barrel * 0.5, 0.);
+            commands.spawn(SpriteBundle {
+                transform: Transform::from_translation(eject),
+                sprite: Sprite { color: Color::rgb(0.85, 0.65, 0.2), custom_size: Some(Vec2::new(2., 4.)), ..default() },
+                ..default()
+            }).insert(ShellCasing {
+                velocity: Vec2::new(angle.cos(), angle.sin()) * CASING_SPEED * rng.random_range(0.7..1.3),
+                spin: rng.random_range(-20. ..20.),
+                timer: Timer::from_seconds(CASING_LIFETIME, TimerMode::Once),
+            });
+        }
+    }
+}
+
+// Bloom shrinks back at each weapon's own rate and the weapon sprite settles
+pub fn recover_recoil(
+    time: Res<Time>,
+    weapons: Res<Weapons>,
+    mut state: ResMut<RecoilState>,
+    mut sprites: Query<&mut Transform, With<WeaponSprite>>,
+) {
+    let dt = time.delta_seconds();
+    for (bloom, stats) in state.bloom.iter_mut().zip(weapons.stats.iter()) {
+        *bloom = (*bloom - stats.feel.bloom_recovery * dt).max(0.);
+    }
+    state.kick *= (1. - RECOIL_RECOVERY * dt).max(0.);
+    for mut t in sprites.iter_mut() {
+        t.translation.y = WEAPON_REST_Y - state.kick;
+    }
+}
+
+pub fn update_casings(mut commands: Commands, time: Res<Time>, mut casings: Query<(Entity, &mut ShellCasing, &mut Transform, &mut Sprite)>) {
+    let dt = time.delta_seconds();
+    for (e, mut casing, mut t, mut sprite) in casings.iter_mut() {
+        casing.timer.tick(time.delta());
+        let velocity = casing.velocity;
+        t.translation += velocity.extend(0.) * dt;
+        t.rotate_z(casing.spin * dt);
+        casing.velocity *= (1. - CASING_FRICTION * dt).max(0.);
+        casing.spin *= (1. - CASING_FRICTION * dt).max(0.);
+        sprite.color.set_a(casing.timer.percent_left());
+        if casing.timer.finished() { commands.entity(e).despawn(); }
+    }
+}
+
+pub fn reset_recoil(
+    mut commands: Commands,
+    mut restarts: EventReader<Restart>,
+    mut state: ResMut<RecoilState>,
+    casings: Query<Entity, With<ShellCasing>>,
+) {
+    for _ in restarts.read() {
+        *state = RecoilState::default();
+        for e in casings.iter() {
+            commands.entity(e).despawn();
+        }
+    }
+}
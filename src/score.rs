@@ -1,7 +1,12 @@
 use bevy::prelude::*;
+use rand::Rng;
+use crate::gameover::Restart;
 
+// Score only ever goes up during a run; coins are earned alongside it and spent in the shop
 #[derive(Resource)] pub struct Score(pub u32);
+#[derive(Resource)] pub struct Coins(pub u32);
 #[derive(Component)] pub struct ScoreText;
+#[derive(Component)] pub struct CoinsText;
 #[derive(Component)] pub struct FloatingScore { pub timer: Timer }
 
 pub fn update_floating_scores(
@@ -25,10 +30,46 @@ pub fn setup_score_ui(mut commands: Commands) {
         style: Style { position_type: PositionType::Absolute, right: Val::Px(10.0), bottom: Val::Px(10.0), ..default() },
         ..default()
     }).insert(ScoreText);
+    commands.spawn(TextBundle {
+        text: Text::from_section(
+            "Coins: 0",
+            TextStyle { font: Default::default(), font_size: 24.0, color: Color::GOLD }
+        ),
+        style: Style { position_type: PositionType::Absolute, right: Val::Px(10.0), bottom: Val::Px(45.0), ..default() },
+        ..default()
+    }).insert(CoinsText);
 }
 
-pub fn update_score_ui(score: Res<Score>, mut query: Query<&mut Text, With<ScoreText>>) {
-    for mut text in query.iter_mut() {
+pub fn update_score_ui(
+    score: Res<Score>,
+    coins: Res<Coins>,
+    mut scores: Query<&mut Text, (With<ScoreText>, Without<CoinsText>)>,
+    mut wallets: Query<&mut Text, With<CoinsText>>,
+) {
+    for mut text in scores.iter_mut() {
         text.sections[0].value = format!("Score: {}", score.0);
     }
+    for mut text in wallets.iter_mut() {
+        text.sections[0].value = format!("Coins: {}", coins.0);
+    }
+}
+
+// Coins carry over like purchased weapons; the score is per run
+pub fn reset_score(mut restarts: EventReader<Restart>, mut score: ResMut<Score>) {
+    for _ in restarts.read() {
+        score.0 = 0;
+    }
+}
+
+pub fn spawn_floating_score(commands: &mut Commands, pos: Vec3, text: &str, color: Color, rng: &mut impl Rng) {
+    spawn_floating_text(commands, pos, text, color, 20., rng);
+}
+
+pub fn spawn_floating_text(commands: &mut Commands, pos: Vec3, text: &str, color: Color, font_size: f32, rng: &mut impl Rng) {
+    let offset = Vec3::new(rng.random_range(-10.0..10.0), rng.random_range(10.0..25.0), 1.);
+    commands.spawn(Text2dBundle {
+        text: Text::from_section(text, TextStyle { font: Default::default(), font_size, color }),
+        transform: Transform::from_translation(pos + offset),
+        ..default()
+    }).insert(FloatingScore { timer: Timer::from_seconds(0.5, TimerMode::Once) });
 }
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use crate::zombie::Zombie;
+
+// ---------------- Constants ----------------
+pub const CELL_SIZE: f32 = 50.0;
+
+// ---------------- Resources ----------------
+// Uniform grid of zombie positions, rebuilt every frame for neighbour/radius lookups
+#[derive(Resource, Default)]
+pub struct SpatialGrid { pub cells: HashMap<(i32, i32), Vec<(Entity, Vec2)>> }
+
+impl SpatialGrid {
+    fn cell(pos: Vec2) -> (i32, i32) {
+        ((pos.x / CELL_SIZE).floor() as i32, (pos.y / CELL_SIZE).floor() as i32)
+    }
+
+    pub fn insert(&mut self, entity: Entity, pos: Vec2) {
+        self.cells.entry(Self::cell(pos)).or_default().push((entity, pos));
+    }
+
+    // Every entry within `radius` of `center`
+    pub fn query_radius(&self, center: Vec2, radius: f32) -> Vec<(Entity, Vec2)> {
+        let (min_x, min_y) = Self::cell(center - Vec2::splat(radius));
+        let (max_x, max_y) = Self::cell(center + Vec2::splat(radius));
+        let mut found = Vec::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                if let Some(cell) = self.cells.get(&(x, y)) {
+                    found.extend(cell.iter().filter(|(_, p)| p.distance(center) <= radius));
+                }
+            }
+        }
+        found
+    }
+}
+
+pub fn rebuild_zombie_grid(mut grid: ResMut<SpatialGrid>, zombies: Query<(Entity, &Transform), With<Zombie>>) {
+    grid.cells.clear();
+    for (e, t) in zombies.iter() {
+        grid.insert(e, t.translation.truncate());
+    }
+}
@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::gameover::Restart;
+use crate::zombie::{self, ZombieFrames, ZombieKind, ZombieStats};
+
+// ---------------- Constants ----------------
+pub const TOP_Y: f32 = 250.0;
+pub const WARNING_TIME: f32 = 0.6;
+pub const BURROW_WARNING_TIME: f32 = 1.5;
+pub const COLUMN_STAGGER: f32 = 0.4;
+
+// ---------------- Patterns ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SpawnPattern {
+    TopEdge,    // single zombie anywhere along the top
+    SideEdges,  // single zombie entering from the left or right edge
+    Burrow,     // surfaces mid-arena after a ground telegraph
+    Horde,      // tight cluster at the top
+    VFormation, // arrowhead led from the front
+    LaneColumn, // single file down one lane
+}
+
+impl SpawnPattern {
+    pub fn group_size(self) -> usize {
+        match self {
+            SpawnPattern::TopEdge | SpawnPattern::SideEdges | SpawnPattern::Burrow => 1,
+            SpawnPattern::Horde => 5,
+            SpawnPattern::VFormation => 5,
+            SpawnPattern::LaneColumn => 4,
+        }
+    }
+
+    fn warning_time(self) -> f32 {
+        match self {
+            SpawnPattern::Burrow => BURROW_WARNING_TIME,
+            _ => WARNING_TIME,
+        }
+    }
+
+    fn warning_color(self) -> Color {
+        match self {
+            SpawnPattern::Burrow => Color::rgba(0.45, 0.3, 0.15, 0.8),
+            _ => Color::rgba(1.0, 0.1, 0.1, 0.8),
+        }
+    }
+
+    // Spawn position and extra delay for each member of a group of `count`
+    fn layout(self, count: usize, rng: &mut impl Rng) -> Vec<(Vec2, f32)> {
+        match self {
+            SpawnPattern::TopEdge => (0..count)
+                .map(|_| (Vec2::new(rng.random_range(-375.0..375.0), TOP_Y), 0.0))
+                .collect(),
+            SpawnPattern::SideEdges => (0..count)
+                .map(|_| {
+                    let side = if rng.random_bool(0.5) { -375.0 } else { 375.0 };
+                    (Vec2::new(side, rng.random_range(0.0..200.0)), 0.0)
+                })
+                .collect(),
+            SpawnPattern::Burrow => (0..count)
+                .map(|_| (Vec2::new(rng.random_range(-300.0..300.0), rng.random_range(-100.0..150.0)), 0.0))
+                .collect(),
+            SpawnPattern::Horde => {
+                let center = Vec2::new(rng.random_range(-300.0..300.0), TOP_Y);
+                (0..count)
+                    .map(|_| (center + Vec2::new(rng.random_range(-30.0..30.0), rng.random_range(-15.0..15.0)), 0.0))
+                    .collect()
+            }
+            SpawnPattern::VFormation => {
+                let leader = Vec2::new(rng.random_range(-300.0..300.0), TOP_Y);
+                (0..count)
+                    .map(|i| {
+                        // 0 leads, then alternate left/right further back
+                        let rank = i.div_ceil(2) as f32;
+                        let side = if i % 2 == 0 { 1.0 } else { -1.0 };
+                        (leader + Vec2::new(side * rank * 25.0, rank * 15.0), 0.0)
+                    })
+                    .collect()
+            }
+            SpawnPattern::LaneColumn => {
+                let x = rng.random_range(-350.0..350.0);
+                (0..count).map(|i| (Vec2::new(x, TOP_Y), i as f32 * COLUMN_STAGGER)).collect()
+            }
+        }
+    }
+}
+
+// ---------------- Components ----------------
+// Telegraph shown where a zombie is about to appear
+#[derive(Component)]
+pub struct SpawnWarning { pub kind: ZombieKind, pub timer: Timer }
+
+// ---------------- Spawning ----------------
+pub fn queue_group(commands: &mut Commands, pattern: SpawnPattern, kinds: &[ZombieKind]) {
+    let mut rng = rand::rng();
+    for (&kind, (pos, delay)) in kinds.iter().zip(pattern.layout(kinds.len(), &mut rng)) {
+        let pos = Vec2::new(pos.x.clamp(-375.0, 375.0), pos.y.min(290.0));
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(pos.extend(-1.0)),
+            sprite: Sprite { color: pattern.warning_color(), custom_size: Some(Vec2::splat(kind.profile().size)), ..default() },
+            ..default()
+        })
+        .insert(SpawnWarning { kind, timer: Timer::from_seconds(pattern.warning_time() + delay, TimerMode::Once) });
+    }
+}
+
+pub fn random_pattern(patterns: &[SpawnPattern], rng: &mut impl Rng) -> SpawnPattern {
+    patterns[rng.random_range(0..patterns.len())]
+}
+
+// Endless mode unlocks patterns as the difficulty level rises
+pub fn patterns_for_level(level: u32) -> Vec<SpawnPattern> {
+    let mut patterns = vec![SpawnPattern::TopEdge, SpawnPattern::TopEdge, SpawnPattern::TopEdge];
+    if level >= 2 { patterns.push(SpawnPattern::SideEdges); }
+    if level >= 3 { patterns.push(SpawnPattern::Horde); }
+    if level >= 4 { patterns.push(SpawnPattern::Burrow); }
+    if level >= 5 { patterns.extend([SpawnPattern::VFormation, SpawnPattern::LaneColumn]); }
+    patterns
+}
+
+pub fn hatch_spawn_warnings(
+    mut commands: Commands,
+    time: Res<Time>,
+    frames: Res<ZombieFrames>,
+    stats: Res<ZombieStats>,
+    mut warnings: Query<(Entity, &mut SpawnWarning, &Transform, &mut Sprite)>,
+) {
+    for (e, mut warning, t, mut sprite) in warnings.iter_mut() {
+        warning.timer.tick(time.delta());
+        // Blink faster as the zombie is about to appear
+        let blink = (warning.timer.elapsed_secs() * (6.0 + 20.0 * warning.timer.percent())).sin() * 0.5 + 0.5;
+        sprite.color.set_a(0.3 + 0.5 * blink);
+
+        if warning.timer.finished() {
+            zombie::spawn_zombie(&mut commands, &frames, &stats, warning.kind, t.translation.truncate().extend(0.0));
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+pub fn clear_spawn_warnings(mut commands: Commands, mut restarts: EventReader<Restart>, warnings: Query<Entity, With<SpawnWarning>>) {
+    for _ in restarts.read() {
+        for e in warnings.iter() {
+            commands.entity(e).despawn();
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use crate::gameover::Restart;
+
+// ---------------- Resources ----------------
+#[derive(Clone, Copy, Default, Debug)]
+pub struct WeaponRunStats { pub hits: u32, pub crits: u32, pub kills: u32, pub damage: f32 }
+
+// Per-run totals, indexed by weapon
+#[derive(Resource, Default)]
+pub struct RunStats { pub weapons: Vec<WeaponRunStats> }
+
+impl RunStats {
+    fn weapon(&mut self, weapon: usize) -> &mut WeaponRunStats {
+        if self.weapons.len() <= weapon {
+            self.weapons.resize(weapon + 1, WeaponRunStats::default());
+        }
+        &mut self.weapons[weapon]
+    }
+
+    pub fn record_hit(&mut self, weapon: usize, damage: f32, crit: bool) {
+        let stats = self.weapon(weapon);
+        stats.hits += 1;
+        stats.crits += crit as u32;
+        stats.damage += damage;
+    }
+
+    pub fn record_kill(&mut self, weapon: usize) {
+        self.weapon(weapon).kills += 1;
+    }
+}
+
+pub fn reset_run_stats(mut restarts: EventReader<Restart>, mut stats: ResMut<RunStats>) {
+    for _ in restarts.read() {
+        *stats = RunStats::default();
+    }
+}
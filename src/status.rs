@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+use crate::boss::Boss;
+use crate::damage::{DamageEvent, DamageType};
+use crate::elite::UprightLabel;
+use crate::zombie::{Zombie, ZombieAttributes};
+
+// ---------------- Constants ----------------
+pub const TICK_INTERVAL: f32 = 0.5;
+pub const SHOCK_VULNERABILITY: f32 = 1.25; // damage taken multiplier while shocked
+
+// ---------------- Status Kinds ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StatusKind { Burning, Slowed, Frozen, Poisoned, Shocked }
+
+impl StatusKind {
+    pub fn icon(self) -> &'static str {
+        match self {
+            StatusKind::Burning => "B",
+            StatusKind::Slowed => "S",
+            StatusKind::Frozen => "F",
+            StatusKind::Poisoned => "P",
+            StatusKind::Shocked => "Z",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            StatusKind::Burning => Color::ORANGE_RED,
+            StatusKind::Slowed => Color::rgb(0.5, 0.7, 1.0),
+            StatusKind::Frozen => Color::CYAN,
+            StatusKind::Poisoned => Color::LIME_GREEN,
+            StatusKind::Shocked => Color::YELLOW,
+        }
+    }
+
+    // Re-applying adds a stack up to this cap; every kind refreshes its duration
+    pub fn max_stacks(self) -> u32 {
+        match self {
+            StatusKind::Burning => 3,
+            StatusKind::Poisoned => 5,
+            _ => 1,
+        }
+    }
+
+    // Damage per stack per second, dealt every `TICK_INTERVAL`
+    pub fn damage_per_second(self) -> f32 {
+        match self {
+            StatusKind::Burning => 8.0,
+            StatusKind::Poisoned => 4.0,
+            StatusKind::Shocked => 2.0,
+            _ => 0.0,
+        }
+    }
+
+    pub fn damage_type(self) -> DamageType {
+        match self {
+            StatusKind::Poisoned => DamageType::Poison,
+            StatusKind::Shocked => DamageType::Electric,
+            _ => DamageType::Fire,
+        }
+    }
+
+    pub fn speed_multiplier(self) -> f32 {
+        match self {
+            StatusKind::Slowed => 0.6,
+            StatusKind::Frozen => 0.0,
+            StatusKind::Shocked => 0.5,
+            _ => 1.0,
+        }
+    }
+
+    // Fire and ice cancel each other out
+    fn cancels(self) -> Option<StatusKind> {
+        match self {
+            StatusKind::Burning => Some(StatusKind::Frozen),
+            StatusKind::Frozen => Some(StatusKind::Burning),
+            _ => None,
+        }
+    }
+}
+
+// ---------------- Components ----------------
+#[derive(Clone, Debug)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub stacks: u32,
+    pub remaining: Timer,
+    pub tick: Timer,
+    pub source: Option<usize>, // weapon index, for run stats
+}
+
+// Every zombie carries one, usually empty
+#[derive(Component, Default)]
+pub struct StatusEffects { pub effects: Vec<StatusEffect> }
+
+impl StatusEffects {
+    pub fn has(&self, kind: StatusKind) -> bool { self.effects.iter().any(|e| e.kind == kind) }
+
+    pub fn apply(&mut self, kind: StatusKind, duration: f32, source: Option<usize>) {
+        if let Some(cancelled) = kind.cancels() {
+            self.effects.retain(|e| e.kind != cancelled);
+        }
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            effect.stacks = (effect.stacks + 1).min(kind.max_stacks());
+            effect.remaining = Timer::from_seconds(duration.max(effect.remaining.remaining_secs()), TimerMode::Once);
+            effect.source = source.or(effect.source);
+        } else {
+            self.effects.push(StatusEffect {
+                kind,
+                stacks: 1,
+                remaining: Timer::from_seconds(duration, TimerMode::Once),
+                tick: Timer::from_seconds(TICK_INTERVAL, TimerMode::Repeating),
+                source,
+            });
+        }
+    }
+}
+
+// Child text above the health bar listing active effects
+#[derive(Component)] pub struct StatusIcons;
+
+// ---------------- Events ----------------
+#[derive(Event)]
+pub struct ApplyStatus { pub target: Entity, pub kind: StatusKind, pub duration: f32, pub source: Option<usize> }
+
+// ---------------- Setup ----------------
+pub fn attach_status(commands: &mut Commands, entity: Entity, size: f32) {
+    commands.entity(entity).insert(StatusEffects::default()).with_children(|parent| {
+        parent.spawn(Text2dBundle {
+            text: Text::default(),
+            ..default()
+        }).insert(StatusIcons).insert(UprightLabel { offset: Vec3::new(0.0, size * 0.8 + 24.0, 2.0) });
+    });
+}
+
+// ---------------- Systems ----------------
+pub fn apply_status_events(mut events: EventReader<ApplyStatus>, mut zombies: Query<&mut StatusEffects>) {
+    for event in events.read() {
+        if let Ok(mut statuses) = zombies.get_mut(event.target) {
+            statuses.apply(event.kind, event.duration, event.source);
+        }
+    }
+}
+
+pub fn tick_statuses(time: Res<Time>, mut zombies: Query<(Entity, &mut StatusEffects)>, mut damage: EventWriter<DamageEvent>) {
+    for (e, mut statuses) in zombies.iter_mut() {
+        for effect in statuses.effects.iter_mut() {
+            effect.remaining.tick(time.delta());
+            let dps = effect.kind.damage_per_second();
+            if dps > 0.0 && effect.tick.tick(time.delta()).just_finished() {
+                let amount = dps * effect.stacks as f32 * TICK_INTERVAL;
+                damage.send(DamageEvent { target: e, amount, kind: effect.kind.damage_type(), source: effect.source, crit: false, direct: false });
+            }
+        }
+        statuses.effects.retain(|effect| !effect.remaining.finished());
+    }
+}
+
+// Runs in `AttributeSet::Modify`
+pub fn apply_status_modifiers(mut zombies: Query<(&StatusEffects, &mut ZombieAttributes)>) {
+    for (statuses, mut attributes) in zombies.iter_mut() {
+        for effect in &statuses.effects {
+            attributes.current.speed *= effect.kind.speed_multiplier();
+            // Frozen zombies can't bite
+            if effect.kind == StatusKind::Frozen {
+                attributes.current.damage = 0.0;
+            }
+        }
+    }
+}
+
+// Tints the zombie by its most recent effect and lists every effect above the health bar.
+// Bosses keep their own phase colours and only show the icons.
+pub fn update_status_visuals(
+    mut zombies: Query<(&Zombie, &StatusEffects, &Children, &mut Sprite, Option<&Boss>)>,
+    mut icons: Query<&mut Text, With<StatusIcons>>,
+) {
+    for (z, statuses, children, mut sprite, boss) in zombies.iter_mut() {
+        if boss.is_none() {
+            let tint = z.kind.profile().tint;
+            sprite.color = match statuses.effects.last() {
+                Some(effect) => {
+                    let c = effect.kind.color();
+                    Color::rgb(tint.r() * c.r(), tint.g() * c.g(), tint.b() * c.b())
+                }
+                None => tint,
+            };
+        }
+
+        for &child in children.iter() {
+            if let Ok(mut text) = icons.get_mut(child) {
+                text.sections = statuses.effects.iter().map(|effect| {
+                    let label = if effect.stacks > 1 { format!("{}{} ", effect.kind.icon(), effect.stacks) } else { format!("{} ", effect.kind.icon()) };
+                    TextSection::new(label, TextStyle { font: Default::default(), font_size: 10.0, color: effect.kind.color() })
+                }).collect();
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::boss::Boss;
+use crate::player::Player;
+use crate::spatial::SpatialGrid;
+use crate::zombie::{Zombie, ZombieAttributes, ZombieKind, SPIT_RANGE};
+
+// ---------------- Constants ----------------
+pub const BARRICADE_Y: f32 = -320.0;
+pub const BACKLINE_Y: f32 = 120.0;
+pub const LANE_DRIFT: f32 = 40.0;
+pub const SEPARATION_RADIUS: f32 = 30.0;
+pub const SEPARATION_WEIGHT: f32 = 1.5;
+pub const WANDER_STRENGTH: f32 = 0.35;
+pub const WANDER_FREQUENCY: f32 = 1.5;
+pub const TURN_RESPONSIVENESS: f32 = 4.0; // how quickly velocity follows the desired heading
+
+// ---------------- Components ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SeekTarget {
+    Barricade, // walk down their lane to the bottom edge
+    Player,    // hunt the player directly
+    Backline,  // support zombies hang back behind the horde
+}
+
+#[derive(Component)]
+pub struct Steering {
+    pub velocity: Vec2,
+    pub target: SeekTarget,
+    pub lane_x: f32,
+    pub wander_phase: f32,
+    pub speed_variance: f32,
+}
+
+impl Steering {
+    pub fn new(kind: ZombieKind, spawn_x: f32, rng: &mut impl Rng) -> Self {
+        let target = match kind {
+            ZombieKind::Runner => SeekTarget::Player,
+            ZombieKind::Healer | ZombieKind::Summoner => SeekTarget::Backline,
+            _ => SeekTarget::Barricade,
+        };
+        Self {
+            velocity: Vec2::ZERO,
+            target,
+            lane_x: (spawn_x + rng.random_range(-LANE_DRIFT..LANE_DRIFT)).clamp(-375.0, 375.0),
+            wander_phase: rng.random_range(0.0..std::f32::consts::TAU),
+            speed_variance: rng.random_range(0.85..1.15),
+        }
+    }
+}
+
+// ---------------- Movement ----------------
+// Bosses drive their own movement in `boss::boss_behaviour`
+pub fn steer_zombies(
+    time: Res<Time>,
+    grid: Res<SpatialGrid>,
+    player: Query<&Transform, (With<Player>, Without<Zombie>)>,
+    mut zombies: Query<(Entity, &Zombie, &ZombieAttributes, &mut Steering, &mut Transform), Without<Boss>>,
+) {
+    let dt = time.delta_seconds();
+    let elapsed = time.elapsed_seconds();
+    let player_pos = player.get_single().map(|t| t.translation.truncate()).ok();
+
+    for (e, z, attributes, mut steering, mut t) in zombies.iter_mut() {
+        let pos = t.translation.truncate();
+        let profile = z.kind.profile();
+        let max_speed = attributes.current.speed * steering.speed_variance;
+
+        // Seek
+        let target = match (steering.target, player_pos) {
+            (SeekTarget::Player, Some(p)) => p,
+            (SeekTarget::Backline, _) => Vec2::new(steering.lane_x, BACKLINE_Y),
+            _ => Vec2::new(steering.lane_x, BARRICADE_Y),
+        };
+        let to_target = target - pos;
+        // Spitters hold position once the player is in range
+        let holding = z.kind == ZombieKind::Spitter && player_pos.is_some_and(|p| p.distance(pos) < SPIT_RANGE);
+        let mut desired = if holding || to_target.length() < 4.0 { Vec2::ZERO } else { to_target.normalize() };
+
+        // Wander: sideways jitter around the seek direction
+        let side = Vec2::new(-desired.y, desired.x);
+        desired += side * WANDER_STRENGTH * (elapsed * WANDER_FREQUENCY + steering.wander_phase).sin();
+
+        // Separation
+        let radius = SEPARATION_RADIUS.max(profile.size);
+        let mut push = Vec2::ZERO;
+        for (other, other_pos) in grid.query_radius(pos, radius) {
+            if other == e { continue; }
+            let away = pos - other_pos;
+            let d = away.length();
+            if d > 0.001 { push += away / d * (1.0 - d / radius); }
+        }
+        desired += push * SEPARATION_WEIGHT;
+
+        let desired_velocity = desired.clamp_length_max(1.0) * max_speed;
+        steering.velocity = steering.velocity.lerp(desired_velocity, (TURN_RESPONSIVENESS * dt).min(1.0));
+        t.translation += steering.velocity.extend(0.0) * dt;
+        t.translation.x = t.translation.x.clamp(-375.0, 375.0);
+
+        // Face the direction of travel
+        if steering.velocity.length_squared() > 1.0 {
+            t.rotation = Quat::from_rotation_z(steering.velocity.y.atan2(steering.velocity.x));
+        }
+    }
+}
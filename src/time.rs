@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use crate::gameover::GameOver;
+use crate::gameover::{GameOver, Restart};
+use crate::wave::{GameMode, WaveDirector, WavePhase};
 
 #[derive(Resource)] pub struct SurvivalTime(pub f32);
 #[derive(Component)] pub struct SurvivalTimerText;
@@ -19,11 +20,26 @@ pub fn update_survival_time(
     time: Res<Time>,
     mut survival_time: ResMut<SurvivalTime>,
     game_over: Res<GameOver>,
+    mode: Res<GameMode>,
+    director: Res<WaveDirector>,
     mut query: Query<&mut Text, With<SurvivalTimerText>>
 ) {
     if !game_over.0 { survival_time.0 += time.delta_seconds(); }
-    
+
+    // During an intermission the timer counts down to the next wave instead
+    let label = if *mode == GameMode::Waves && director.phase == WavePhase::Intermission {
+        format!("Next wave in {:.1} s", director.intermission.remaining_secs())
+    } else {
+        format!("Time: {:.1} s", survival_time.0)
+    };
+
     for mut text in query.iter_mut() {
-        text.sections[0].value = format!("Time: {:.1} s", survival_time.0);
+        text.sections[0].value = label.clone();
+    }
+}
+
+pub fn reset_survival_time(mut restarts: EventReader<Restart>, mut survival_time: ResMut<SurvivalTime>) {
+    for _ in restarts.read() {
+        survival_time.0 = 0.0;
     }
 }
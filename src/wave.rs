@@ -0,0 +1,213 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use crate::gameover::{GameOver, Restart};
+use crate::spawn_pattern::{self, SpawnPattern, SpawnWarning};
+use crate::zombie::{
+    Zombie,
+    ZombieKind,
+    ZombieStats,
+    INITIAL_ZOMBIE_SPEED,
+    INITIAL_ZOMBIE_HEALTH,
+    SPEED_INCREMENT,
+    HEALTH_INCREMENT,
+};
+
+// ---------------- Constants ----------------
+pub const FIRST_WAVE_DELAY: f32 = 3.0;
+pub const INTERMISSION_SECONDS: f32 = 15.0;
+pub const BOSS_WAVE_EVERY: u32 = 5;
+
+// ---------------- Resources ----------------
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GameMode { Waves, Endless }
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WavePhase {
+    Intermission, // shop open, counting down to the next wave
+    Spawning,     // zombies from the wave queue are still arriving
+    Clearing,     // everything spawned, waiting for the last zombie to die
+}
+
+pub struct WaveDefinition {
+    pub spawns: Vec<(ZombieKind, u32)>, // how many of each archetype
+    pub spawn_interval: f32,            // seconds between spawns, per zombie
+    pub patterns: Vec<SpawnPattern>,    // picked at random for each spawn group
+    pub boss: bool,                     // a boss arrives after the rest of the wave
+}
+
+#[derive(Resource)]
+pub struct WaveDirector {
+    pub wave: u32,
+    pub phase: WavePhase,
+    pub queue: Vec<ZombieKind>,
+    pub patterns: Vec<SpawnPattern>,
+    pub spawn_interval: f32,
+    pub spawn_timer: Timer,
+    pub intermission: Timer,
+}
+
+#[derive(Component)] pub struct WaveText;
+
+impl Default for WaveDirector {
+    fn default() -> Self {
+        Self {
+            wave: 0,
+            phase: WavePhase::Intermission,
+            queue: Vec::new(),
+            patterns: vec![SpawnPattern::TopEdge],
+            spawn_interval: 1.0,
+            spawn_timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+            intermission: Timer::from_seconds(FIRST_WAVE_DELAY, TimerMode::Once),
+        }
+    }
+}
+
+impl WaveDirector {
+    // Purchases are only allowed between waves; endless mode keeps the shop open
+    pub fn shop_open(&self, mode: GameMode) -> bool {
+        mode == GameMode::Endless || self.phase == WavePhase::Intermission
+    }
+}
+
+// ---------------- Wave Definitions ----------------
+pub fn wave_definition(wave: u32) -> WaveDefinition {
+    WaveDefinition {
+        spawns: vec![
+            (ZombieKind::Walker, 5 + wave * 2),
+            (ZombieKind::Runner, wave.saturating_sub(1)),
+            (ZombieKind::Exploder, if wave >= 3 { wave / 2 } else { 0 }),
+            (ZombieKind::Tank, if wave >= 4 { wave / 3 } else { 0 }),
+            (ZombieKind::Spitter, if wave >= 4 { wave / 3 } else { 0 }),
+            (ZombieKind::Splitter, if wave >= 6 { wave / 3 } else { 0 }),
+            (ZombieKind::Healer, if wave >= 5 { wave / 5 } else { 0 }),
+            (ZombieKind::Summoner, if wave >= 7 { wave / 7 } else { 0 }),
+        ],
+        spawn_interval: (2.0 - 0.1 * wave as f32).max(0.5),
+        patterns: [
+            (1, SpawnPattern::TopEdge),
+            (2, SpawnPattern::Horde),
+            (3, SpawnPattern::SideEdges),
+            (4, SpawnPattern::LaneColumn),
+            (5, SpawnPattern::VFormation),
+            (6, SpawnPattern::Burrow),
+        ].into_iter().filter(|&(from, _)| wave >= from).map(|(_, pattern)| pattern).collect(),
+        boss: wave.is_multiple_of(BOSS_WAVE_EVERY),
+    }
+}
+
+fn start_wave(director: &mut WaveDirector, stats: &mut ZombieStats) {
+    director.wave += 1;
+    let definition = wave_definition(director.wave);
+
+    director.queue = definition.spawns.iter()
+        .flat_map(|&(kind, count)| std::iter::repeat_n(kind, count as usize))
+        .collect();
+    director.queue.shuffle(&mut rand::rng());
+    if definition.boss {
+        // The queue is popped from the back, so the boss spawns last
+        director.queue.insert(0, ZombieKind::Boss);
+    }
+    director.patterns = definition.patterns;
+    director.spawn_interval = definition.spawn_interval;
+    director.spawn_timer = Timer::from_seconds(definition.spawn_interval, TimerMode::Once);
+    director.phase = WavePhase::Spawning;
+
+    // Each wave is a fixed difficulty step instead of the endless ramp timer
+    let step = (director.wave - 1) as f32;
+    stats.speed = INITIAL_ZOMBIE_SPEED + SPEED_INCREMENT * 0.5 * step;
+    stats.health = INITIAL_ZOMBIE_HEALTH + HEALTH_INCREMENT * step;
+    stats.level = director.wave - 1;
+
+    println!("Wave {} started ({} zombies)", director.wave, director.queue.len());
+}
+
+// ---------------- Director ----------------
+#[allow(clippy::too_many_arguments)]
+pub fn advance_waves(
+    mut commands: Commands,
+    time: Res<Time>,
+    mode: Res<GameMode>,
+    game_over: Res<GameOver>,
+    mut director: ResMut<WaveDirector>,
+    mut stats: ResMut<ZombieStats>,
+    zombies: Query<(), With<Zombie>>,
+    warnings: Query<(), With<SpawnWarning>>,
+) {
+    if *mode != GameMode::Waves || game_over.0 { return; }
+
+    match director.phase {
+        WavePhase::Intermission => {
+            if director.intermission.tick(time.delta()).finished() {
+                start_wave(&mut director, &mut stats);
+            }
+        }
+        WavePhase::Spawning => {
+            if director.spawn_timer.tick(time.delta()).just_finished() {
+                let mut rng = rand::rng();
+                let (pattern, group) = if director.queue.last() == Some(&ZombieKind::Boss) {
+                    // Bosses always arrive alone
+                    director.queue.pop();
+                    (SpawnPattern::TopEdge, vec![ZombieKind::Boss])
+                } else {
+                    let pattern = spawn_pattern::random_pattern(&director.patterns, &mut rng);
+                    let mut group = Vec::new();
+                    while group.len() < pattern.group_size() && director.queue.last().is_some_and(|&k| k != ZombieKind::Boss) {
+                        group.extend(director.queue.pop());
+                    }
+                    (pattern, group)
+                };
+                spawn_pattern::queue_group(&mut commands, pattern, &group);
+
+                // Bigger groups buy a longer pause before the next one
+                let pause = director.spawn_interval * group.len() as f32;
+                director.spawn_timer = Timer::from_seconds(pause, TimerMode::Once);
+                if director.queue.is_empty() {
+                    director.phase = WavePhase::Clearing;
+                }
+            }
+        }
+        WavePhase::Clearing => {
+            if zombies.is_empty() && warnings.is_empty() {
+                println!("Wave {} cleared", director.wave);
+                director.phase = WavePhase::Intermission;
+                director.intermission = Timer::from_seconds(INTERMISSION_SECONDS, TimerMode::Once);
+            }
+        }
+    }
+}
+
+pub fn reset_waves(mut restarts: EventReader<Restart>, mut director: ResMut<WaveDirector>) {
+    for _ in restarts.read() {
+        *director = WaveDirector::default();
+    }
+}
+
+// ---------------- HUD ----------------
+pub fn setup_wave_ui(mut commands: Commands) {
+    commands.spawn(TextBundle {
+        text: Text::from_section(
+            "Wave 0",
+            TextStyle { font: Default::default(), font_size: 30.0, color: Color::WHITE }
+        ),
+        style: Style { position_type: PositionType::Absolute, right: Val::Px(10.0), top: Val::Px(10.0), ..default() },
+        ..default()
+    }).insert(WaveText);
+}
+
+pub fn update_wave_ui(
+    mode: Res<GameMode>,
+    director: Res<WaveDirector>,
+    zombies: Query<(), With<Zombie>>,
+    warnings: Query<(), With<SpawnWarning>>,
+    mut query: Query<&mut Text, With<WaveText>>,
+) {
+    let label = match (*mode, director.phase) {
+        (GameMode::Endless, _) => "Endless".to_string(),
+        (GameMode::Waves, WavePhase::Intermission) => format!("Wave {} incoming - Shop open", director.wave + 1),
+        (GameMode::Waves, _) => format!("Wave {} - {} left", director.wave, director.queue.len() + warnings.iter().count() + zombies.iter().count()),
+    };
+
+    for mut text in query.iter_mut() {
+        text.sections[0].value = label.clone();
+    }
+}
@@ -1,23 +1,549 @@
 use bevy::prelude::*;
-use crate::score::Score;
+use crate::damage::{Damage, DamageType};
+use crate::gameover::Restart;
+use crate::player::BULLET_SPEED;
+use crate::projectile::{OnExpire, Projectile};
+use crate::minigun::{HeatBar, HEAT_BAR_WIDTH};
+use crate::score::Coins;
+use crate::status::StatusKind;
+use crate::wave::{GameMode, WaveDirector};
 
 #[derive(Component)]
 pub struct WeaponButton {
     pub index: usize,
 }
 
-#[derive(Resource, Clone)]
-pub struct WeaponAssets {
-    pub lock_texture: Handle<Image>,
-    pub weapon_textures: Vec<Handle<Image>>,
+#[derive(Component)]
+pub struct AmmoText {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct ReloadBar {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct LevelText {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct ShopPanel;
+
+pub const MAX_WEAPON_LEVEL: u32 = 5;
+
+
+// Weapon indices
+pub const PISTOL: usize = 0;
+pub const MINIGUN: usize = 2;
+
+pub const WEAPON_IMAGES: [&str; 9] = [
+    "images/pistol.png", "images/shotgun.png", "images/minigun.png", "images/rocket.png",
+    "images/sniper.png", "images/laser.png", "images/flamethrower.png", "images/tesla.png", "images/missile.png",
+];
+pub const WEAPON_KEYS: [KeyCode; 9] = [
+    KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4, KeyCode::Key5, KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FireMode {
+    Projectile, // spawns bullets moved by `projectile::move_projectiles`
+    Hitscan,    // instant ray that pierces up to `pierce` extra zombies
+    Beam,       // continuous ray hitting everything in range each shot while held
+    Chain,      // arc to the nearest zombie, then jumps `chain_jumps` times losing `chain_decay` each jump
+    Homing,     // projectiles steer towards a target; salvos alternate nearest and weakest
+}
+
+// Secondary fire on `alt_fire::ALT_FIRE_KEY`, driven by `alt_fire::alt_fire`
+#[derive(Clone, Copy, Debug)]
+pub enum AltFire {
+    ChargedShot { charge_time: f32, max_multiplier: f32 }, // hold to charge, release to fire
+    Slug { damage: f32, pierce: u32 },                     // single heavy round
+    ExplosiveBurst { rounds: u32, interval: f32, blast_radius: f32 },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AltFireStats {
+    pub name: &'static str,
+    pub mode: AltFire,
+    pub cooldown: f32,
+    pub ammo_cost: u32, // rounds taken from the magazine
+}
+
+// How `FireMode::Projectile` and `FireMode::Homing` bullets fly
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectileStats {
+    pub speed: f32,
+    pub acceleration: Vec2, // world-space, along the firing axis for boosters
+    pub lifetime: f32,
+    pub bounces: u32,       // wall ricochets
+}
+
+impl ProjectileStats {
+    pub const fn straight(speed: f32) -> Self {
+        Self { speed, acceleration: Vec2::ZERO, lifetime: 2.0, bounces: 0 }
+    }
+}
+
+// Recoil, bloom, flash and casings for each shot; see `recoil`
+#[derive(Clone, Copy, Debug)]
+pub struct WeaponFeel {
+    pub barrel: f32,         // muzzle distance above the player, where shots leave from
+    pub recoil: f32,         // kick of the weapon sprite, pixels
+    pub bloom: f32,          // extra spread added per shot, radians
+    pub max_bloom: f32,
+    pub bloom_recovery: f32, // radians per second
+    pub flash: f32,          // muzzle flash size; 0 for none
+    pub casings: u32,        // shells ejected per shot
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct WeaponStats {
+    pub name: &'static str,
+    pub damage: f32,             // per projectile
+    pub damage_type: DamageType,
+    pub fire_mode: FireMode,
+    pub crit_chance: f32,
+    pub crit_multiplier: f32, // 1.0 for weapons that never crit, including explosives
+    pub fire_interval: f32,      // seconds between shots
+    pub automatic: bool,         // keeps firing while Space is held
+    pub pellets: u32,            // projectiles per shot
+    pub spread: f32,             // width of the cone pellets are scattered across, in radians
+    pub projectile: ProjectileStats,
+    pub range: f32,              // distance a projectile travels before expiring
+    pub falloff: f32,            // fraction of damage lost by the end of its range
+    pub knockback: f32,          // push on zombies hit at close range
+    pub blast_radius: f32,       // projectiles explode on impact when non-zero
+    pub status: Option<(StatusKind, f32)>, // effect and duration applied on hit
+    pub chain_jumps: u32,        // chain weapons: extra zombies the arc jumps to
+    pub chain_decay: f32,        // chain weapons: fraction of damage lost per jump
+    pub alt_fire: Option<AltFireStats>,
+    pub feel: WeaponFeel,
+    pub color: Color,
+    pub magazine: u32,           // rounds per magazine
+    pub max_reserve: Option<u32>, // None for unlimited reserve
+    pub reload_time: f32,
+    pub ammo_cost: u32,          // shop price for one magazine of reserve
+    pub pierce: u32,             // extra zombies each projectile passes through
+    pub upgrade: UpgradeStep,
+    pub upgrade_cost: u32,       // price of the first upgrade; scales with level
+}
+
+// What one upgrade level adds to a weapon
+#[derive(Clone, Copy, Debug)]
+pub struct UpgradeStep {
+    pub damage: f32,        // multiplier
+    pub fire_interval: f32, // multiplier
+    pub magazine: u32,
+    pub pellets: u32,
+    pub spread: f32,        // added to the cone, negative tightens it
+    pub pierce_every: u32,  // +1 pierce every this many levels, 0 for none
+}
+
+impl WeaponStats {
+    // Stats after the upgrade taking the weapon from `level` to `level + 1`
+    pub fn upgraded(&self, level: u32) -> WeaponStats {
+        let step = self.upgrade;
+        let mut next = *self;
+        next.damage *= step.damage;
+        next.fire_interval *= step.fire_interval;
+        next.magazine += step.magazine;
+        next.pellets += step.pellets;
+        next.spread = (next.spread + step.spread).max(0.0);
+        if step.pierce_every > 0 && (level + 1).is_multiple_of(step.pierce_every) {
+            next.pierce += 1;
+        }
+        next
+    }
+
+    // Per-hit damage for the weapon at `source`
+    pub fn damage_for(&self, source: usize) -> Damage {
+        let direct = self.fire_mode != FireMode::Beam && self.damage_type != DamageType::Fire;
+        Damage { amount: self.damage, kind: self.damage_type, crit_chance: self.crit_chance, crit_multiplier: self.crit_multiplier, source: Some(source), direct }
+    }
+
+    // Flight data for one bullet fired along `direction`
+    pub fn projectile(&self, direction: Vec2) -> Projectile {
+        let mut projectile = Projectile::new(direction * self.projectile.speed, self.range, self.projectile.lifetime);
+        projectile.acceleration = self.projectile.acceleration;
+        projectile.falloff = self.falloff;
+        projectile.bounces = self.projectile.bounces;
+        projectile.pierce = self.pierce;
+        if self.blast_radius > 0.0 {
+            projectile.on_expire = OnExpire::Explode(self.blast_radius);
+        }
+        projectile
+    }
+}
+
+// Rounds loaded and in reserve for one weapon
+#[derive(Clone, Copy, Debug)]
+pub struct Ammo {
+    pub loaded: u32,
+    pub reserve: Option<u32>, // None never runs out
 }
 
+impl Ammo {
+    pub fn full(stats: &WeaponStats) -> Self {
+        Ammo { loaded: stats.magazine, reserve: stats.max_reserve }
+    }
+
+    pub fn can_reload(&self, stats: &WeaponStats) -> bool {
+        self.loaded < stats.magazine && self.reserve != Some(0)
+    }
+
+    // Moves rounds from reserve into the magazine
+    fn reload(&mut self, stats: &WeaponStats) {
+        let missing = stats.magazine - self.loaded;
+        let taken = self.reserve.map_or(missing, |reserve| reserve.min(missing));
+        self.loaded += taken;
+        if let Some(reserve) = self.reserve.as_mut() { *reserve -= taken; }
+    }
+}
+
+pub fn base_weapon_stats() -> Vec<WeaponStats> {
+    // What every weapon below starts from; each one only lists what it changes
+    let base = WeaponStats {
+        name: "",
+        damage: 0.0,
+        damage_type: DamageType::Ballistic,
+        fire_mode: FireMode::Projectile,
+        crit_chance: 0.0,
+        crit_multiplier: 1.0,
+        fire_interval: 1.0,
+        automatic: false,
+        pellets: 1,
+        spread: 0.0,
+        projectile: ProjectileStats::straight(BULLET_SPEED),
+        range: 800.0,
+        falloff: 0.0,
+        knockback: 0.0,
+        blast_radius: 0.0,
+        status: None,
+        chain_jumps: 0,
+        chain_decay: 0.0,
+        alt_fire: None,
+        feel: WeaponFeel {
+            barrel: 30.0,
+            recoil: 0.0,
+            bloom: 0.0,
+            max_bloom: 0.0,
+            bloom_recovery: 0.0,
+            flash: 0.0,
+            casings: 0,
+        },
+        color: Color::WHITE,
+        magazine: 1,
+        max_reserve: None,
+        reload_time: 2.0,
+        ammo_cost: 60,
+        pierce: 0,
+        upgrade: UpgradeStep {
+            damage: 1.2,
+            fire_interval: 0.9,
+            magazine: 1,
+            pellets: 0,
+            spread: 0.0,
+            pierce_every: 0,
+        },
+        upgrade_cost: 200,
+    };
+    vec![
+        WeaponStats {
+            name: "Pistol",
+            damage: 30.0,
+            crit_chance: 0.10,
+            crit_multiplier: 2.0,
+            fire_interval: 0.25,
+            alt_fire: Some(AltFireStats {
+                name: "Charged Shot",
+                mode: AltFire::ChargedShot { charge_time: 1.2, max_multiplier: 4.0 },
+                cooldown: 2.0,
+                ammo_cost: 3,
+            }),
+            feel: WeaponFeel {
+                barrel: 28.0,
+                recoil: 3.0,
+                bloom: 0.03,
+                max_bloom: 0.12,
+                bloom_recovery: 0.4,
+                flash: 10.0,
+                casings: 1,
+            },
+            color: Color::YELLOW,
+            magazine: 12,
+            reload_time: 1.0,
+            ammo_cost: 0,
+            upgrade: UpgradeStep {
+                magazine: 2,
+                pierce_every: 2,
+                ..base.upgrade
+            },
+            upgrade_cost: 75,
+            ..base
+        },
+        WeaponStats {
+            name: "Shotgun",
+            damage: 18.0,
+            crit_chance: 0.05,
+            crit_multiplier: 1.5,
+            fire_interval: 0.8,
+            pellets: 5,
+            spread: 0.5,
+            projectile: ProjectileStats { bounces: 1, ..ProjectileStats::straight(BULLET_SPEED) },
+            range: 350.0,
+            falloff: 0.6,
+            knockback: 350.0,
+            alt_fire: Some(AltFireStats {
+                name: "Slug",
+                mode: AltFire::Slug { damage: 90.0, pierce: 3 },
+                cooldown: 1.5,
+                ammo_cost: 2,
+            }),
+            feel: WeaponFeel {
+                barrel: 32.0,
+                recoil: 7.0,
+                bloom: 0.05,
+                max_bloom: 0.15,
+                bloom_recovery: 0.3,
+                flash: 18.0,
+                casings: 1,
+            },
+            color: Color::ORANGE,
+            magazine: 6,
+            max_reserve: Some(36),
+            reload_time: 1.8,
+            ammo_cost: 30,
+            upgrade: UpgradeStep {
+                damage: 1.15,
+                fire_interval: 0.92,
+                pellets: 1,
+                spread: -0.03,
+                ..base.upgrade
+            },
+            upgrade_cost: 120,
+            ..base
+        },
+        WeaponStats {
+            name: "Minigun",
+            damage: 12.0,
+            crit_chance: 0.03,
+            crit_multiplier: 1.5,
+            fire_interval: 0.05,
+            automatic: true,
+            range: 700.0,
+            falloff: 0.2,
+            alt_fire: Some(AltFireStats {
+                name: "Explosive Burst",
+                mode: AltFire::ExplosiveBurst { rounds: 5, interval: 0.08, blast_radius: 45.0 },
+                cooldown: 4.0,
+                ammo_cost: 20,
+            }),
+            feel: WeaponFeel {
+                barrel: 34.0,
+                recoil: 1.5,
+                bloom: 0.01,
+                max_bloom: 0.25,
+                bloom_recovery: 0.5,
+                flash: 12.0,
+                casings: 1,
+            },
+            color: Color::YELLOW,
+            magazine: 100,
+            max_reserve: Some(400),
+            reload_time: 3.0,
+            ammo_cost: 50,
+            upgrade: UpgradeStep {
+                damage: 1.15,
+                magazine: 25,
+                pierce_every: 3,
+                ..base.upgrade
+            },
+            upgrade_cost: 150,
+            ..base
+        },
+        WeaponStats {
+            name: "Rocket Launcher",
+            damage: 80.0,
+            damage_type: DamageType::Explosive,
+            fire_interval: 1.2,
+            projectile: ProjectileStats { acceleration: Vec2::new(0.0, 1200.0), ..ProjectileStats::straight(300.0) },
+            range: 600.0,
+            blast_radius: 90.0,
+            feel: WeaponFeel {
+                recoil: 6.0,
+                flash: 20.0,
+                ..base.feel
+            },
+            color: Color::RED,
+            max_reserve: Some(12),
+            reload_time: 1.5,
+            ..base
+        },
+        WeaponStats {
+            name: "Sniper Rifle",
+            damage: 120.0,
+            fire_mode: FireMode::Hitscan,
+            crit_chance: 0.25,
+            crit_multiplier: 3.0,
+            fire_interval: 1.3,
+            range: 900.0,
+            feel: WeaponFeel {
+                barrel: 36.0,
+                recoil: 8.0,
+                flash: 14.0,
+                casings: 1,
+                ..base.feel
+            },
+            magazine: 5,
+            max_reserve: Some(30),
+            reload_time: 2.2,
+            ammo_cost: 50,
+            pierce: 3,
+            upgrade: UpgradeStep {
+                fire_interval: 0.92,
+                pierce_every: 2,
+                ..base.upgrade
+            },
+            ..base
+        },
+        WeaponStats {
+            name: "Laser",
+            damage: 6.0,
+            fire_mode: FireMode::Beam,
+            fire_interval: 0.08,
+            automatic: true,
+            range: 500.0,
+            status: Some((StatusKind::Slowed, 0.4)),
+            color: Color::CYAN,
+            magazine: 60,
+            max_reserve: Some(240),
+            reload_time: 2.5,
+            upgrade: UpgradeStep {
+                fire_interval: 1.0,
+                magazine: 15,
+                ..base.upgrade
+            },
+            upgrade_cost: 220,
+            ..base
+        },
+        WeaponStats {
+            name: "Flamethrower",
+            damage: 4.0,
+            damage_type: DamageType::Fire,
+            fire_interval: 0.06,
+            automatic: true,
+            pellets: 2,
+            spread: 0.6,
+            range: 160.0,
+            falloff: 0.5,
+            status: Some((StatusKind::Burning, 3.0)),
+            feel: WeaponFeel {
+                recoil: 0.5,
+                ..base.feel
+            },
+            color: Color::ORANGE,
+            magazine: 100,
+            max_reserve: Some(300),
+            reload_time: 2.5,
+            ammo_cost: 50,
+            pierce: 1,
+            upgrade: UpgradeStep {
+                fire_interval: 1.0,
+                magazine: 25,
+                spread: 0.05,
+                pierce_every: 2,
+                ..base.upgrade
+            },
+            ..base
+        },
+        WeaponStats {
+            name: "Tesla Gun",
+            damage: 40.0,
+            damage_type: DamageType::Electric,
+            fire_mode: FireMode::Chain,
+            crit_chance: 0.05,
+            crit_multiplier: 2.0,
+            fire_interval: 0.7,
+            range: 300.0,
+            status: Some((StatusKind::Shocked, 1.5)),
+            chain_jumps: 3,
+            chain_decay: 0.3,
+            feel: WeaponFeel {
+                recoil: 2.0,
+                flash: 14.0,
+                ..base.feel
+            },
+            color: Color::rgb(0.6, 0.8, 1.0),
+            magazine: 8,
+            max_reserve: Some(40),
+            upgrade: UpgradeStep {
+                damage: 1.15,
+                fire_interval: 0.92,
+                magazine: 2,
+                ..base.upgrade
+            },
+            upgrade_cost: 250,
+            ..base
+        },
+        WeaponStats {
+            name: "Missile Launcher",
+            damage: 45.0,
+            damage_type: DamageType::Explosive,
+            fire_mode: FireMode::Homing,
+            fire_interval: 0.6,
+            pellets: 2,
+            spread: 0.8,
+            projectile: ProjectileStats { lifetime: 3.0, ..ProjectileStats::straight(420.0) },
+            range: 2000.0,
+            blast_radius: 50.0,
+            feel: WeaponFeel {
+                recoil: 3.0,
+                bloom: 0.04,
+                max_bloom: 0.2,
+                bloom_recovery: 0.3,
+                flash: 16.0,
+                ..base.feel
+            },
+            color: Color::rgb(1.0, 0.6, 0.2),
+            magazine: 6,
+            max_reserve: Some(36),
+            upgrade: UpgradeStep {
+                damage: 1.15,
+                fire_interval: 0.92,
+                magazine: 2,
+                pellets: 1,
+                spread: 0.1,
+                ..base.upgrade
+            },
+            upgrade_cost: 250,
+            ..base
+        },
+    ]
+}
 
 #[derive(Resource)]
 pub struct Weapons {
-    pub purchased: Vec<bool>, // unlocked/purchased weapons
-    pub active: usize,        // index of currently selected weapon
-    pub costs: Vec<u32>,      // cost for each weapon
+    pub purchased: Vec<bool>,       // unlocked/purchased weapons
+    pub active: usize,              // index of currently selected weapon
+    pub costs: Vec<u32>,            // cost for each weapon
+    pub stats: Vec<WeaponStats>,    // damage and handling for each weapon, at its current level
+    pub levels: Vec<u32>,           // upgrade level for each weapon, starting at 1
+    pub ammo: Vec<Ammo>,            // rounds left for each weapon
+    pub reloading: Option<(usize, Timer)>, // weapon being reloaded
+}
+
+impl Weapons {
+    pub fn is_reloading(&self) -> bool {
+        self.reloading.as_ref().is_some_and(|(i, _)| *i == self.active)
+    }
+
+    // None once the weapon is fully upgraded
+    pub fn upgrade_cost(&self, index: usize) -> Option<u32> {
+        let level = self.levels[index];
+        (level < MAX_WEAPON_LEVEL).then(|| self.stats[index].upgrade_cost * level)
+    }
 }
 
 pub fn setup_weapons(
@@ -25,27 +551,22 @@ pub fn setup_weapons(
     asset_server: Res<AssetServer>,
 ) {
     let lock_texture = asset_server.load("images/lock.png");
-    let weapon_images = vec![
-        asset_server.load("images/pistol.png"),    // pistol
-        asset_server.load("images/shotgun.png"),   // shotgun
-        asset_server.load("images/minigun.png"),   // minigun
-    ];
+    let weapon_images: Vec<Handle<Image>> = WEAPON_IMAGES.iter().map(|path| asset_server.load(*path)).collect();
 
-    let costs = vec![0, 100, 200]; // pistol is free, others have a cost
+    let costs = vec![0, 100, 200, 300, 250, 350, 300, 400, 350]; // pistol is free, others have a cost
 
     // Weapons resource
+    let stats = base_weapon_stats();
     commands.insert_resource(Weapons {
-        purchased: vec![true, false, false], // pistol unlocked by default
-        active: 0,                            // pistol selected by default
+        purchased: (0..stats.len()).map(|i| i == PISTOL).collect(), // pistol unlocked by default
+        active: PISTOL,                                             // pistol selected by default
         costs: costs.clone(),
+        ammo: stats.iter().map(Ammo::full).collect(),
+        levels: vec![1; stats.len()],
+        stats,
+        reloading: None,
     });
 
-    let weapon_assets = WeaponAssets {
-    lock_texture: lock_texture.clone(), // clone here
-    weapon_textures: weapon_images.clone(),
-};
-commands.insert_resource(weapon_assets);
-
 
     // Spawn buttons
     for i in 0..weapon_images.len() {
@@ -112,33 +633,116 @@ commands.insert_resource(weapon_assets);
                 left: Val::Px(3.0),
                 ..default()
             });
+
+            // Upgrade level in the top corner
+            parent.spawn(TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 10.0,
+                    color: Color::GOLD,
+                    ..default()
+                },
+            ))
+            .insert(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(2.0),
+                right: Val::Px(3.0),
+                ..default()
+            })
+            .insert(LevelText { index: i });
+
+            // Ammo counter beside the button
+            parent.spawn(TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 14.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ))
+            .insert(Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(56.0),
+                top: Val::Px(8.0),
+                ..default()
+            })
+            .insert(AmmoText { index: i });
+
+            // Reload progress bar under the counter
+            parent.spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(56.0),
+                    top: Val::Px(28.0),
+                    width: Val::Px(0.0),
+                    height: Val::Px(4.0),
+                    ..default()
+                },
+                background_color: Color::WHITE.into(),
+                ..default()
+            })
+            .insert(ReloadBar { index: i });
+
+            if i == MINIGUN {
+                // Heat gauge with a dark track behind it
+                parent.spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(56.0),
+                        top: Val::Px(36.0),
+                        width: Val::Px(HEAT_BAR_WIDTH),
+                        height: Val::Px(6.0),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.0, 0.0, 0.0, 0.5).into(),
+                    ..default()
+                });
+                parent.spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(56.0),
+                        top: Val::Px(36.0),
+                        width: Val::Px(0.0),
+                        height: Val::Px(6.0),
+                        ..default()
+                    },
+                    background_color: Color::ORANGE.into(),
+                    ..default()
+                })
+                .insert(HeatBar);
+            }
         });
     }
+
+    // Upgrade details for the active weapon, shown while the shop is open
+    commands.spawn(TextBundle {
+        text: Text::from_section("", TextStyle { font: Default::default(), font_size: 16.0, color: Color::WHITE }),
+        style: Style { position_type: PositionType::Absolute, right: Val::Px(10.0), top: Val::Px(50.0), ..default() },
+        background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+        ..default()
+    }).insert(ShopPanel);
 }
 
 pub fn handle_weapon_input(
     keyboard: Res<Input<KeyCode>>,
     mut weapons: ResMut<Weapons>,
-    mut score: ResMut<Score>,
+    mut coins: ResMut<Coins>,
+    mode: Res<GameMode>,
+    director: Res<WaveDirector>,
 ) {
-    for i in 0..weapons.purchased.len() {
-        let key = match i {
-            0 => KeyCode::Key1,
-            1 => KeyCode::Key2,
-            2 => KeyCode::Key3,
-            _ => continue,
-        };
-
+    for (i, &key) in WEAPON_KEYS.iter().enumerate() {
         if keyboard.just_pressed(key) {
             if !weapons.purchased[i] {
                 // Attempt purchase
-                if score.0 >= weapons.costs[i] {
-                    score.0 -= weapons.costs[i];
+                if !director.shop_open(*mode) {
+                    println!("The shop opens between waves");
+                } else if coins.0 >= weapons.costs[i] {
+                    coins.0 -= weapons.costs[i];
                     weapons.purchased[i] = true;
                     weapons.active = i;
                     println!("Purchased and selected weapon {}", i + 1);
                 } else {
-                    println!("Not enough score to purchase weapon {}", i + 1);
+                    println!("Not enough coins to purchase weapon {}", i + 1);
                 }
             } else {
                 weapons.active = i;
@@ -146,6 +750,74 @@ pub fn handle_weapon_input(
             }
         }
     }
+
+    // Upgrade the active weapon one level
+    if keyboard.just_pressed(KeyCode::U) {
+        let active = weapons.active;
+        let name = weapons.stats[active].name;
+        match weapons.upgrade_cost(active) {
+            None => println!("{} is fully upgraded", name),
+            Some(_) if !director.shop_open(*mode) => println!("The shop opens between waves"),
+            Some(cost) if coins.0 >= cost => {
+                coins.0 -= cost;
+                let level = weapons.levels[active];
+                weapons.stats[active] = weapons.stats[active].upgraded(level);
+                weapons.levels[active] += 1;
+                println!("Upgraded {} to level {}", name, level + 1);
+            }
+            Some(_) => println!("Not enough coins to upgrade {}", name),
+        }
+    }
+
+    // Buy a magazine's worth of reserve ammo for the active weapon
+    if keyboard.just_pressed(KeyCode::B) {
+        let active = weapons.active;
+        let stats = weapons.stats[active];
+        let Some(max_reserve) = stats.max_reserve else {
+            println!("{} has unlimited ammo", stats.name);
+            return;
+        };
+        let reserve = weapons.ammo[active].reserve.unwrap_or(0);
+        if !director.shop_open(*mode) {
+            println!("The shop opens between waves");
+        } else if reserve >= max_reserve {
+            println!("{} ammo is full", stats.name);
+        } else if coins.0 >= stats.ammo_cost {
+            coins.0 -= stats.ammo_cost;
+            weapons.ammo[active].reserve = Some((reserve + stats.magazine).min(max_reserve));
+            println!("Bought {} ammo", stats.name);
+        } else {
+            println!("Not enough coins to buy {} ammo", stats.name);
+        }
+    }
+}
+
+// R reloads the active weapon; an empty magazine reloads on its own
+pub fn reload_weapons(keyboard: Res<Input<KeyCode>>, time: Res<Time>, mut weapons: ResMut<Weapons>) {
+    // Switching weapons cancels a reload in progress
+    if weapons.reloading.is_some() && !weapons.is_reloading() {
+        weapons.reloading = None;
+    }
+
+    let active = weapons.active;
+    let stats = weapons.stats[active];
+    let ammo = weapons.ammo[active];
+    if weapons.reloading.is_none() && ammo.can_reload(&stats) && (keyboard.just_pressed(KeyCode::R) || ammo.loaded == 0) {
+        weapons.reloading = Some((active, Timer::from_seconds(stats.reload_time, TimerMode::Once)));
+    }
+
+    if let Some((_, timer)) = weapons.reloading.as_mut() && timer.tick(time.delta()).finished() {
+        weapons.ammo[active].reload(&stats);
+        weapons.reloading = None;
+    }
+}
+
+// Weapons stay unlocked between runs but start each run with full ammo
+pub fn reset_ammo(mut restarts: EventReader<Restart>, mut weapons: ResMut<Weapons>) {
+    for _ in restarts.read() {
+        weapons.ammo = weapons.stats.iter().map(Ammo::full).collect();
+        weapons.reloading = None;
+    }
 }
 
 pub fn update_weapon_ui(
@@ -178,3 +850,84 @@ pub fn update_weapon_ui(
         }
     }
 }
+
+pub fn update_ammo_ui(
+    weapons: Res<Weapons>,
+    mut texts: Query<(&AmmoText, &mut Text)>,
+    mut bars: Query<(&ReloadBar, &mut Style)>,
+) {
+    for (ammo_text, mut text) in texts.iter_mut() {
+        let i = ammo_text.index;
+        let ammo = weapons.ammo[i];
+        text.sections[0].value = if !weapons.purchased[i] {
+            String::new()
+        } else if let Some(reserve) = ammo.reserve {
+            format!("{}/{}", ammo.loaded, reserve)
+        } else {
+            format!("{}/inf", ammo.loaded)
+        };
+        text.sections[0].style.color = if ammo.loaded == 0 { Color::RED } else { Color::WHITE };
+    }
+
+    for (bar, mut style) in bars.iter_mut() {
+        let progress = match &weapons.reloading {
+            Some((i, timer)) if *i == bar.index => timer.percent(),
+            _ => 0.0,
+        };
+        style.width = Val::Px(40.0 * progress);
+    }
+}
+
+pub fn update_shop_panel(
+    weapons: Res<Weapons>,
+    mode: Res<GameMode>,
+    director: Res<WaveDirector>,
+    mut panels: Query<(&mut Text, &mut Visibility), With<ShopPanel>>,
+    mut levels: Query<(&LevelText, &mut Text), Without<ShopPanel>>,
+) {
+    for (level_text, mut text) in levels.iter_mut() {
+        text.sections[0].value = if weapons.purchased[level_text.index] {
+            format!("Lv{}", weapons.levels[level_text.index])
+        } else {
+            String::new()
+        };
+    }
+
+    let active = weapons.active;
+    let current = &weapons.stats[active];
+    let level = weapons.levels[active];
+    let label = match weapons.upgrade_cost(active) {
+        None => format!("{} Lv{} (max)", current.name, level),
+        Some(cost) => {
+            let next = current.upgraded(level);
+            let mut lines = vec![
+                format!("{} Lv{} -> Lv{}", current.name, level, level + 1),
+                format!("Damage {:.0} -> {:.0}", current.damage, next.damage),
+                format!("Fire rate {:.1}/s -> {:.1}/s", 1.0 / current.fire_interval, 1.0 / next.fire_interval),
+                format!("Magazine {} -> {}", current.magazine, next.magazine),
+            ];
+            if next.pellets != current.pellets {
+                lines.push(format!("Pellets {} -> {}", current.pellets, next.pellets));
+            }
+            if next.spread != current.spread {
+                lines.push(format!("Spread {:.2} -> {:.2}", current.spread, next.spread));
+            }
+            if next.pierce != current.pierce {
+                lines.push(format!("Pierce {} -> {}", current.pierce, next.pierce));
+            }
+            lines.push(format!("[U] Upgrade: {}$", cost));
+            lines.join("\n")
+        }
+    };
+    let ammo_line = match current.max_reserve {
+        Some(_) => format!("\n[B] Ammo: {}$", current.ammo_cost),
+        None => String::new(),
+    };
+
+    let open = director.shop_open(*mode) && weapons.purchased[active];
+    for (mut text, mut visibility) in panels.iter_mut() {
+        text.sections[0].value = format!("{}{}", label, ammo_line);
+        *visibility = if open { Visibility::Visible } else { Visibility::Hidden };
+    }
+}
+
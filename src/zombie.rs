@@ -1,5 +1,16 @@
 use bevy::prelude::*;
 use rand::Rng;
+use crate::boss::Boss;
+use crate::damage::{PlayerDamageEvent, ZombieKilled};
+use crate::explosion::Explosion;
+use crate::aura::{Aura, AuraEffect};
+use crate::elite::{self, UprightLabel};
+use crate::gameover::Restart;
+use crate::player::Player;
+use crate::spawn_pattern;
+use crate::status;
+use crate::steering::Steering;
+use crate::wave::GameMode;
 
 // ---------------- Constants ----------------
 pub const INITIAL_ZOMBIE_SPEED: f32 = 50.0;
@@ -9,19 +20,101 @@ pub const RAMP_INTERVAL: f32 = 8.0;
 pub const SPEED_INCREMENT: f32 = 10.0;
 pub const SPAWN_DECREMENT: f32 = 0.2;
 pub const HEALTH_INCREMENT: f32 = 20.0;
+pub const CONTACT_DPS: f32 = 15.0;
+pub const SPIT_RANGE: f32 = 280.0;
+pub const SPIT_INTERVAL: f32 = 2.5;
+pub const SPIT_SPEED: f32 = 220.0;
+pub const SPIT_DAMAGE: f32 = 10.0;
+pub const HEAL_RADIUS: f32 = 90.0;
+pub const HEAL_INTERVAL: f32 = 2.0;
+pub const HEAL_FRACTION: f32 = 0.15;
+pub const MINION_SUMMON_INTERVAL: f32 = 6.0;
+pub const MINION_SUMMON_COUNT: usize = 3;
+pub const MINION_SUMMON_CAP: usize = 6;   // live minions per summoner
+pub const EXPLOSION_RADIUS: f32 = 80.0;
+pub const EXPLOSION_DAMAGE: f32 = 40.0;
+pub const EXPLOSION_PLAYER_DAMAGE: f32 = 20.0;
+
+// ---------------- Archetypes ----------------
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ZombieKind { Walker, Runner, Tank, Exploder, Splitter, Splitling, Spitter, Healer, Summoner, Minion, Boss }
+
+pub struct ZombieProfile {
+    pub health: f32, // multiplier on `ZombieStats.health`
+    pub speed: f32,  // multiplier on `ZombieStats.speed`
+    pub size: f32,
+    pub damage: f32, // multiplier on `CONTACT_DPS`
+    pub armour: f32, // fraction of incoming damage ignored
+    pub reward: u32, // total score for a kill, including the +10 of the killing hit; also paid out as coins
+    pub tint: Color,
+}
+
+impl ZombieKind {
+    pub fn profile(self) -> ZombieProfile {
+        match self {
+            ZombieKind::Walker => ZombieProfile { health: 1.0, speed: 1.0, size: 25.0, damage: 1.0, armour: 0.0, reward: 100, tint: Color::WHITE },
+            ZombieKind::Runner => ZombieProfile { health: 0.5, speed: 2.2, size: 20.0, damage: 0.7, armour: 0.0, reward: 120, tint: Color::rgb(0.6, 1.0, 0.6) },
+            ZombieKind::Tank => ZombieProfile { health: 4.0, speed: 0.5, size: 40.0, damage: 2.0, armour: 0.5, reward: 300, tint: Color::rgb(0.55, 0.6, 0.85) },
+            ZombieKind::Exploder => ZombieProfile { health: 0.8, speed: 1.2, size: 25.0, damage: 1.0, armour: 0.0, reward: 150, tint: Color::rgb(1.0, 0.55, 0.2) },
+            ZombieKind::Splitter => ZombieProfile { health: 1.5, speed: 0.9, size: 32.0, damage: 1.0, armour: 0.0, reward: 150, tint: Color::rgb(0.75, 0.45, 1.0) },
+            ZombieKind::Splitling => ZombieProfile { health: 0.4, speed: 1.5, size: 16.0, damage: 0.5, armour: 0.0, reward: 40, tint: Color::rgb(0.85, 0.65, 1.0) },
+            ZombieKind::Spitter => ZombieProfile { health: 0.8, speed: 1.0, size: 25.0, damage: 0.5, armour: 0.0, reward: 180, tint: Color::rgb(0.8, 1.0, 0.2) },
+            ZombieKind::Healer => ZombieProfile { health: 1.2, speed: 0.8, size: 25.0, damage: 0.5, armour: 0.0, reward: 250, tint: Color::rgb(0.3, 1.0, 0.5) },
+            ZombieKind::Summoner => ZombieProfile { health: 1.5, speed: 0.7, size: 28.0, damage: 0.5, armour: 0.0, reward: 250, tint: Color::rgb(0.6, 0.3, 0.9) },
+            ZombieKind::Minion => ZombieProfile { health: 0.3, speed: 1.6, size: 15.0, damage: 0.4, armour: 0.0, reward: 30, tint: Color::rgb(0.5, 0.4, 0.6) },
+            ZombieKind::Boss => ZombieProfile { health: 20.0, speed: 0.5, size: 70.0, damage: 3.0, armour: 0.0, reward: 2000, tint: Color::WHITE },
+        }
+    }
+
+    // Endless mode unlocks archetypes as the difficulty level rises
+    pub fn random_for_level(level: u32, rng: &mut impl Rng) -> Self {
+        let mut pool = vec![ZombieKind::Walker, ZombieKind::Walker, ZombieKind::Walker];
+        if level >= 1 { pool.push(ZombieKind::Runner); }
+        if level >= 2 { pool.push(ZombieKind::Exploder); }
+        if level >= 3 { pool.extend([ZombieKind::Tank, ZombieKind::Spitter]); }
+        if level >= 4 { pool.push(ZombieKind::Healer); }
+        if level >= 5 { pool.push(ZombieKind::Splitter); }
+        if level >= 6 { pool.push(ZombieKind::Summoner); }
+        pool[rng.random_range(0..pool.len())]
+    }
+}
 
 // ---------------- Components ----------------
 #[derive(Component)]
-pub struct Zombie { pub current_frame: usize, pub timer: Timer, pub health: f32, pub max_health: f32 }
+pub struct Zombie { pub kind: ZombieKind, pub current_frame: usize, pub timer: Timer, pub health: f32, pub max_health: f32 }
 
 #[derive(Component)]
 pub struct HealthBar;
 
+#[derive(Clone, Copy, Debug)]
+pub struct Attributes { pub speed: f32, pub damage: f32, pub armour: f32 }
+
+// Snapshotted at spawn so difficulty ramps only affect new zombies.
+// `current` is rebuilt from `base` every frame, then adjusted by systems in `AttributeSet::Modify`.
+#[derive(Component)]
+pub struct ZombieAttributes { pub base: Attributes, pub current: Attributes }
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AttributeSet { Reset, Modify }
+
+#[derive(Component)]
+pub struct Spitter { pub timer: Timer }
+
+#[derive(Component)]
+pub struct SpitProjectile { pub velocity: Vec3 }
+
+#[derive(Component)]
+pub struct Summoner { pub timer: Timer, pub minions: Vec<Entity> }
+
+// Short-lived translucent flash that fades out from `alpha`
+#[derive(Component)]
+pub struct Blast { pub timer: Timer, pub alpha: f32 }
+
 // ---------------- Resources ----------------
 #[derive(Resource)] pub struct ZombieSpawnTimer(pub Timer);
 #[derive(Resource)] pub struct ZombieFrames(pub Vec<Handle<Image>>);
 #[derive(Resource)]
-pub struct ZombieStats { pub speed: f32, pub spawn_interval: f32, pub health: f32, pub ramp_timer: Timer }
+pub struct ZombieStats { pub speed: f32, pub spawn_interval: f32, pub health: f32, pub level: u32, pub ramp_timer: Timer }
 
 // ---------------- Startup ----------------
 pub fn setup_zombie_stats(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -29,52 +122,256 @@ pub fn setup_zombie_stats(mut commands: Commands, asset_server: Res<AssetServer>
         speed: INITIAL_ZOMBIE_SPEED,
         spawn_interval: INITIAL_SPAWN_INTERVAL,
         health: INITIAL_ZOMBIE_HEALTH,
+        level: 0,
         ramp_timer: Timer::from_seconds(RAMP_INTERVAL, TimerMode::Repeating),
     });
 
-    let frames = (0..16).map(|i| asset_server.load(&format!("tds_zombie/export/skeleton-move_{}.png", i))).collect();
+    let frames = (0..16).map(|i| asset_server.load(format!("tds_zombie/export/skeleton-move_{}.png", i))).collect();
     commands.insert_resource(ZombieFrames(frames));
 }
 
+pub fn reset_zombie_stats(mut restarts: EventReader<Restart>, mut stats: ResMut<ZombieStats>, mut timer: ResMut<ZombieSpawnTimer>) {
+    for _ in restarts.read() {
+        stats.speed = INITIAL_ZOMBIE_SPEED;
+        stats.spawn_interval = INITIAL_SPAWN_INTERVAL;
+        stats.health = INITIAL_ZOMBIE_HEALTH;
+        stats.level = 0;
+        stats.ramp_timer.reset();
+
+        timer.0.set_duration(std::time::Duration::from_secs_f32(INITIAL_SPAWN_INTERVAL));
+        timer.0.reset();
+    }
+}
+
 // ---------------- Difficulty ----------------
-pub fn ramp_zombie_difficulty(time: Res<Time>, mut stats: ResMut<ZombieStats>, mut timer: ResMut<ZombieSpawnTimer>) {
+// Endless mode only: waves set their own difficulty in `wave::advance_waves`.
+pub fn ramp_zombie_difficulty(time: Res<Time>, mode: Res<GameMode>, mut stats: ResMut<ZombieStats>, mut timer: ResMut<ZombieSpawnTimer>) {
+    if *mode != GameMode::Endless { return; }
     if stats.ramp_timer.tick(time.delta()).just_finished() {
         stats.speed += SPEED_INCREMENT;
         stats.spawn_interval = (stats.spawn_interval - SPAWN_DECREMENT).max(0.5);
         stats.health += HEALTH_INCREMENT;
+        stats.level += 1;
         timer.0.set_duration(std::time::Duration::from_secs_f32(stats.spawn_interval));
     }
 }
 
 // ---------------- Spawning ----------------
-pub fn spawn_zombies(mut commands: Commands, time: Res<Time>, mut timer: ResMut<ZombieSpawnTimer>, frames: Res<ZombieFrames>, stats: Res<ZombieStats>) {
-    if timer.0.tick(time.delta()).just_finished() {
-        let x = rand::thread_rng().random_range(-375.0..375.0);
+pub fn spawn_zombie(commands: &mut Commands, frames: &ZombieFrames, stats: &ZombieStats, kind: ZombieKind, translation: Vec3) -> Entity {
+    let profile = kind.profile();
+    let health = stats.health * profile.health;
+    let size = profile.size;
+    let mut rng = rand::rng();
 
-        commands.spawn(SpriteBundle {
-            texture: frames.0[0].clone(),
-            transform: Transform {
-                translation: Vec3::new(x, 250.0, 0.0),
-                rotation: Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2),
-                ..default()
-            },
-            sprite: Sprite { custom_size: Some(Vec2::splat(25.0)), ..default() },
+    // Bosses and splitter offspring never roll elite affixes
+    let affixes = match kind {
+        ZombieKind::Boss | ZombieKind::Splitling | ZombieKind::Minion => Vec::new(),
+        _ => elite::roll_affixes(stats.level, &mut rng),
+    };
+    let attributes = elite::apply_affixes(
+        Attributes { speed: stats.speed * profile.speed, damage: CONTACT_DPS * profile.damage, armour: profile.armour },
+        &affixes,
+    );
+
+    let entity = commands.spawn(SpriteBundle {
+        texture: frames.0[0].clone(),
+        transform: Transform {
+            translation,
+            rotation: Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2),
+            ..default()
+        },
+        sprite: Sprite { color: profile.tint, custom_size: Some(Vec2::splat(size)), ..default() },
+        ..default()
+    })
+    .insert(ZombieAttributes { base: attributes, current: attributes })
+    .insert(Zombie { kind, current_frame: 0, timer: Timer::from_seconds(0.1, TimerMode::Repeating), health, max_health: health })
+    .with_children(|parent| {
+        // Bosses show their health on the top-of-screen bar instead
+        if kind == ZombieKind::Boss { return; }
+        parent.spawn(SpriteBundle {
+            transform: Transform::from_xyz(0.0, size * 0.8, 1.0),
+            sprite: Sprite { color: Color::RED, custom_size: Some(Vec2::new(25.0, 4.0)), ..default() },
             ..default()
-        })
-        .insert(Zombie { current_frame: 0, timer: Timer::from_seconds(0.1, TimerMode::Repeating), health: stats.health, max_health: stats.health })
-        .with_children(|parent| {
-            parent.spawn(SpriteBundle {
-                transform: Transform::from_xyz(0.0, 20.0, 1.0),
-                sprite: Sprite { color: Color::RED, custom_size: Some(Vec2::new(25.0, 4.0)), ..default() },
+        }).insert(HealthBar);
+    })
+    .id();
+
+    match kind {
+        ZombieKind::Boss => { commands.entity(entity).insert(Boss::default()); }
+        ZombieKind::Spitter => { commands.entity(entity).insert(Spitter { timer: Timer::from_seconds(SPIT_INTERVAL, TimerMode::Repeating) }); }
+        ZombieKind::Healer => {
+            commands.entity(entity).insert(Aura::new(HEAL_RADIUS, AuraEffect::Heal(HEAL_FRACTION), HEAL_INTERVAL));
+            attach_role_label(commands, entity, "HEALER", Color::LIME_GREEN, size);
+        }
+        ZombieKind::Summoner => {
+            commands.entity(entity).insert(Summoner { timer: Timer::from_seconds(MINION_SUMMON_INTERVAL, TimerMode::Repeating), minions: Vec::new() });
+            attach_role_label(commands, entity, "SUMMONER", Color::VIOLET, size);
+        }
+        _ => {}
+    }
+    if !affixes.is_empty() {
+        elite::attach_elite(commands, entity, affixes, health, size);
+    }
+    status::attach_status(commands, entity, size);
+    // Bosses drive their own movement; everything else steers
+    if kind != ZombieKind::Boss {
+        commands.entity(entity).insert(Steering::new(kind, translation.x, &mut rng));
+    }
+    entity
+}
+
+// Support zombies are flagged so players learn to focus them first
+fn attach_role_label(commands: &mut Commands, entity: Entity, label: &str, color: Color, size: f32) {
+    commands.entity(entity).with_children(|parent| {
+        parent.spawn(Text2dBundle {
+            text: Text::from_section(label, TextStyle { font: Default::default(), font_size: 10.0, color }),
+            ..default()
+        }).insert(UprightLabel { offset: Vec3::new(0.0, -(size * 0.5 + 8.0), 2.0) });
+    });
+}
+
+// Endless mode trickle spawner
+pub fn spawn_zombies(mut commands: Commands, time: Res<Time>, mode: Res<GameMode>, mut timer: ResMut<ZombieSpawnTimer>, stats: Res<ZombieStats>) {
+    if *mode != GameMode::Endless { return; }
+    if timer.0.tick(time.delta()).just_finished() {
+        let mut rng = rand::rng();
+        let pattern = spawn_pattern::random_pattern(&spawn_pattern::patterns_for_level(stats.level), &mut rng);
+        let group: Vec<ZombieKind> = (0..pattern.group_size()).map(|_| ZombieKind::random_for_level(stats.level, &mut rng)).collect();
+        spawn_pattern::queue_group(&mut commands, pattern, &group);
+    }
+}
+
+// ---------------- Attributes ----------------
+pub fn reset_zombie_attributes(mut query: Query<&mut ZombieAttributes>) {
+    for mut attributes in query.iter_mut() {
+        attributes.current = attributes.base;
+    }
+}
+
+// ---------------- Contact ----------------
+pub fn zombie_contact_damage(
+    time: Res<Time>,
+    zombies: Query<(&Zombie, &ZombieAttributes, &Transform)>,
+    player: Query<&Transform, (With<Player>, Without<Zombie>)>,
+    mut player_damage: EventWriter<PlayerDamageEvent>,
+) {
+    let Ok(player_t) = player.get_single() else { return };
+    let dps: f32 = zombies.iter()
+        .filter(|(z, _, t)| t.translation.truncate().distance(player_t.translation.truncate()) < z.kind.profile().size * 0.5 + 8.0)
+        .map(|(_, a, _)| a.current.damage)
+        .sum();
+    if dps > 0.0 {
+        player_damage.send(PlayerDamageEvent { amount: dps * time.delta_seconds() });
+    }
+}
+
+// ---------------- Spitters ----------------
+pub fn spitter_attack(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut spitters: Query<(&mut Spitter, &Transform)>,
+    player: Query<&Transform, With<Player>>,
+) {
+    let Ok(player_t) = player.get_single() else { return };
+    for (mut spitter, t) in spitters.iter_mut() {
+        if t.translation.distance(player_t.translation) >= SPIT_RANGE { continue; }
+        if spitter.timer.tick(time.delta()).just_finished() {
+            // Aimed at where the player is now, so moving dodges it
+            let velocity = (player_t.translation - t.translation).normalize_or_zero() * SPIT_SPEED;
+            commands.spawn(SpriteBundle {
+                transform: Transform::from_translation(t.translation.truncate().extend(0.5)),
+                sprite: Sprite { color: Color::rgb(0.5, 0.9, 0.1), custom_size: Some(Vec2::splat(8.0)), ..default() },
                 ..default()
-            }).insert(HealthBar);
+            }).insert(SpitProjectile { velocity });
+        }
+    }
+}
+
+pub fn move_spit(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut spit: Query<(Entity, &mut Transform, &SpitProjectile)>,
+    player: Query<&Transform, (With<Player>, Without<SpitProjectile>)>,
+    mut player_damage: EventWriter<PlayerDamageEvent>,
+) {
+    let player_pos = player.get_single().map(|t| t.translation).ok();
+    for (e, mut t, s) in spit.iter_mut() {
+        t.translation += s.velocity * time.delta_seconds();
+        if player_pos.is_some_and(|p| p.truncate().distance(t.translation.truncate()) < 12.0) {
+            player_damage.send(PlayerDamageEvent { amount: SPIT_DAMAGE });
+            commands.entity(e).despawn();
+        } else if t.translation.y < -300.0 || t.translation.x.abs() > 400.0 {
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+// ---------------- Death Effects ----------------
+pub fn explode_on_death(mut killed: EventReader<ZombieKilled>, mut explosions: EventWriter<Explosion>) {
+    for event in killed.read() {
+        if event.kind != ZombieKind::Exploder { continue; }
+        explosions.send(Explosion {
+            position: event.position,
+            radius: EXPLOSION_RADIUS,
+            damage: EXPLOSION_DAMAGE,
+            player_damage: EXPLOSION_PLAYER_DAMAGE,
+            source: None,
+            status: None,
         });
     }
 }
 
-// ---------------- Movement ----------------
-pub fn move_zombies(mut query: Query<&mut Transform, With<Zombie>>, stats: Res<ZombieStats>, time: Res<Time>) {
-    for mut t in query.iter_mut() { t.translation.y -= stats.speed * time.delta_seconds(); }
+pub fn split_on_death(
+    mut commands: Commands,
+    mut killed: EventReader<ZombieKilled>,
+    frames: Res<ZombieFrames>,
+    stats: Res<ZombieStats>,
+) {
+    for event in killed.read() {
+        if event.kind != ZombieKind::Splitter { continue; }
+        for dx in [-12.0, 12.0] {
+            let pos = Vec3::new((event.position.x + dx).clamp(-375.0, 375.0), event.position.y, 0.0);
+            spawn_zombie(&mut commands, &frames, &stats, ZombieKind::Splitling, pos);
+        }
+    }
+}
+
+// ---------------- Summoners ----------------
+pub fn summon_minions(
+    mut commands: Commands,
+    time: Res<Time>,
+    frames: Res<ZombieFrames>,
+    stats: Res<ZombieStats>,
+    mut summoners: Query<(&mut Summoner, &Transform)>,
+    zombies: Query<(), With<Zombie>>,
+) {
+    let mut rng = rand::rng();
+    for (mut summoner, t) in summoners.iter_mut() {
+        if !summoner.timer.tick(time.delta()).just_finished() { continue; }
+        // Only top the pack back up to the cap
+        summoner.minions.retain(|&minion| zombies.contains(minion));
+        let count = MINION_SUMMON_COUNT.min(MINION_SUMMON_CAP - summoner.minions.len());
+        if count == 0 { continue; }
+        for i in 0..count {
+            let angle = std::f32::consts::TAU * i as f32 / count as f32 + rng.random_range(0.0..1.0);
+            let pos = t.translation.truncate() + Vec2::from_angle(angle) * 30.0;
+            let minion = spawn_zombie(&mut commands, &frames, &stats, ZombieKind::Minion, Vec3::new(pos.x.clamp(-375.0, 375.0), pos.y, 0.0));
+            summoner.minions.push(minion);
+        }
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(t.translation.truncate().extend(-0.5)),
+            sprite: Sprite { color: Color::rgba(0.6, 0.3, 0.9, 0.3), custom_size: Some(Vec2::splat(70.0)), ..default() },
+            ..default()
+        }).insert(Blast { timer: Timer::from_seconds(0.4, TimerMode::Once), alpha: 0.3 });
+    }
+}
+
+pub fn fade_blasts(mut commands: Commands, time: Res<Time>, mut blasts: Query<(Entity, &mut Blast, &mut Sprite)>) {
+    for (e, mut blast, mut sprite) in blasts.iter_mut() {
+        blast.timer.tick(time.delta());
+        sprite.color.set_a(blast.alpha * blast.timer.percent_left());
+        if blast.timer.finished() { commands.entity(e).despawn(); }
+    }
 }
 
 // ---------------- Health Bars ----------------